@@ -1,4 +1,4 @@
-use i18nrs::yew::use_translation;
+use i18nrs::yew::{use_translation, LanguageSelector, LanguageSelectorVariant};
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
@@ -223,6 +223,24 @@ pub fn tooltip_example() -> Html {
     }
 }
 
+#[function_component(BuiltinLanguageSelector)]
+pub fn builtin_language_selector() -> Html {
+    let (i18n, _set_language) = use_translation();
+
+    html! {
+        <>
+            <LanguageSelector class="w-full border rounded-md p-2 mb-4" />
+            <LanguageSelector
+                variant={LanguageSelectorVariant::Buttons}
+                class="flex gap-2 mb-4"
+                button_class="px-3 py-1 rounded bg-gray-200 hover:bg-gray-300"
+                active_class="bg-blue-500 text-white"
+            />
+            <h1 class="text-2xl font-semibold text-gray-700">{ i18n.t("greeting") }</h1>
+        </>
+    }
+}
+
 #[function_component(Examples)]
 pub fn examples() -> Html {
     html! {
@@ -552,6 +570,37 @@ pub fn tooltip_example() -> Html {
                     </pre>
                     <TooltipExample />
                 </div>
+                // Built-in Language Selector
+                <div class="flex flex-col items-center bg-gray-50 p-6 rounded-lg shadow-lg">
+                    <h2 class="text-xl font-semibold mb-4 text-gray-800">
+                        { "Built-in Language Selector" }
+                    </h2>
+                    <pre
+                        class="font-mono text-xs text-gray-200 bg-gray-800 p-4 rounded-md w-full mb-4 overflow-x-auto"
+                    >
+                        { r#"use yew::prelude::*;
+use i18nrs::yew::{use_translation, LanguageSelector, LanguageSelectorVariant};
+
+#[function_component(BuiltinLanguageSelector)]
+pub fn builtin_language_selector() -> Html {
+    let (i18n, _set_language) = use_translation();
+
+    html! {
+        <>
+            <LanguageSelector class="w-full border rounded-md p-2 mb-4" />
+            <LanguageSelector
+                variant={LanguageSelectorVariant::Buttons}
+                class="flex gap-2 mb-4"
+                button_class="px-3 py-1 rounded bg-gray-200 hover:bg-gray-300"
+                active_class="bg-blue-500 text-white"
+            />
+            <h1 class="text-2xl font-semibold text-gray-700">{ i18n.t("greeting") }</h1>
+        </>
+    }
+}"# }
+                    </pre>
+                    <BuiltinLanguageSelector />
+                </div>
             </div>
         </div>
     }
@@ -0,0 +1,235 @@
+//! Request-scoped locales for `axum` and other `tower` based servers.
+//!
+//! The `I18nLayer` resolves the language of each request and attaches a `Locale` handle to the
+//! request extensions. Handlers extract the `Locale` to translate keys in that language, while all
+//! requests share a single `I18n` catalog.
+
+use crate::config::I18n;
+use crate::cookie::parse_cookie;
+use crate::negotiate::{match_language, negotiate_accept_language};
+use axum::extract::FromRequestParts;
+use axum::http::header::{ACCEPT_LANGUAGE, COOKIE};
+use axum::http::request::Parts;
+use axum::http::{Request, StatusCode};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+/// Enum representing where the language of a request is looked up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocaleSource {
+    /// A query parameter with the given name (e.g., `?lang=fr`).
+    Query(String),
+    /// A cookie with the given name, such as the one written by `StorageType::Cookie`.
+    Cookie(String),
+    /// The `Accept-Language` header, negotiated with quality weights and BCP 47 matching.
+    Header,
+}
+
+/// A cheap, cloneable handle to the shared catalog, bound to the language of a request.
+#[derive(Debug, Clone)]
+pub struct Locale {
+    i18n: Arc<I18n>,
+    language: &'static str,
+}
+
+impl Locale {
+    /// Retrieves the language resolved for the request.
+    ///
+    /// # Returns
+    /// - The language code as a `&str`.
+    pub fn language(&self) -> &str {
+        self.language
+    }
+
+    /// Translates a given key in the language of the request.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key to retrieve (e.g., `"errors.required"`).
+    ///
+    /// # Returns
+    /// - The translated string, or a fallback message if the key does not exist.
+    pub fn t(&self, key: &str) -> String {
+        self.i18n.translate(self.language, key)
+    }
+
+    /// Retrieves the shared catalog.
+    pub fn i18n(&self) -> &I18n {
+        &self.i18n
+    }
+}
+
+impl<S> FromRequestParts<S> for Locale
+where
+    S: Send + Sync,
+{
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts.extensions.get::<Locale>().cloned().ok_or((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "No Locale found; is the I18nLayer installed?",
+        ))
+    }
+}
+
+/// A `tower` layer resolving the language of each request.
+///
+/// # Examples
+/// ```rust
+/// use axum::{body::Body, http::Request, routing::get, Router};
+/// use i18nrs::axum::{I18nLayer, Locale};
+/// use i18nrs::{I18n, I18nConfig};
+/// use std::collections::HashMap;
+/// use tower::ServiceExt;
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let translations = HashMap::from([
+///         ("en", r#"{"errors": {"required": "This field is required"}}"#),
+///         ("fr", r#"{"errors": {"required": "Ce champ est obligatoire"}}"#),
+///     ]);
+///     let i18n = I18n::new(
+///         I18nConfig {
+///             languages: vec!["en", "fr"],
+///             translations: translations.clone(),
+///             ..Default::default()
+///         },
+///         translations,
+///     )
+///     .unwrap();
+///
+///     let app = Router::new()
+///         .route("/", get(|locale: Locale| async move { locale.t("errors.required") }))
+///         .layer(I18nLayer::new(i18n));
+///
+///     let request = Request::builder()
+///         .uri("/")
+///         .header("Accept-Language", "fr-CH, fr;q=0.9, en;q=0.8")
+///         .body(Body::empty())
+///         .unwrap();
+///     let response = app.oneshot(request).await.unwrap();
+///     let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+///
+///     assert_eq!(body, "Ce champ est obligatoire");
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct I18nLayer {
+    i18n: Arc<I18n>,
+    sources: Arc<[LocaleSource]>,
+}
+
+impl I18nLayer {
+    /// Creates a layer sharing the given catalog.
+    ///
+    /// The language is looked up, in order, in the `lang` query parameter, the `i18nrs` cookie and the
+    /// `Accept-Language` header. Requests matching none of them use the catalog's current language.
+    ///
+    /// # Arguments
+    /// - `i18n`: The catalog shared by all requests.
+    pub fn new(i18n: I18n) -> Self {
+        I18nLayer {
+            i18n: Arc::new(i18n),
+            sources: Arc::from([
+                LocaleSource::Query("lang".to_string()),
+                LocaleSource::Cookie("i18nrs".to_string()),
+                LocaleSource::Header,
+            ]),
+        }
+    }
+
+    /// Replaces the sources consulted, in order, to resolve the language.
+    ///
+    /// # Arguments
+    /// - `sources`: The `LocaleSource`s to consult; the first one yielding a supported language wins.
+    pub fn sources(mut self, sources: Vec<LocaleSource>) -> Self {
+        self.sources = Arc::from(sources);
+        self
+    }
+
+    /// Resolves the language of a request.
+    ///
+    /// # Arguments
+    /// - `request`: The request to inspect.
+    ///
+    /// # Returns
+    /// - A `Locale` bound to the resolved language.
+    pub fn resolve<B>(&self, request: &Request<B>) -> Locale {
+        let languages = &self.i18n.config.languages;
+        let headers = request.headers();
+
+        let language = self
+            .sources
+            .iter()
+            .find_map(|source| match source {
+                LocaleSource::Query(name) => request
+                    .uri()
+                    .query()?
+                    .split('&')
+                    .filter_map(|pair| pair.split_once('='))
+                    .find(|(key, _)| key == name)
+                    .and_then(|(_, value)| match_language(value, languages)),
+                LocaleSource::Cookie(name) => headers
+                    .get_all(COOKIE)
+                    .iter()
+                    .filter_map(|value| value.to_str().ok())
+                    .find_map(|cookies| parse_cookie(cookies, name))
+                    .and_then(|value| match_language(&value, languages)),
+                LocaleSource::Header => headers
+                    .get(ACCEPT_LANGUAGE)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| negotiate_accept_language(value, languages).best),
+            })
+            .or_else(|| {
+                let current = self.i18n.get_current_language();
+                languages
+                    .iter()
+                    .copied()
+                    .find(|language| *language == current)
+            })
+            .unwrap_or_default();
+
+        Locale {
+            i18n: self.i18n.clone(),
+            language,
+        }
+    }
+}
+
+impl<S> Layer<S> for I18nLayer {
+    type Service = I18nService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        I18nService {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+/// The `tower` service produced by `I18nLayer`.
+#[derive(Debug, Clone)]
+pub struct I18nService<S> {
+    inner: S,
+    layer: I18nLayer,
+}
+
+impl<S, B> Service<Request<B>> for I18nService<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<B>) -> Self::Future {
+        let locale = self.layer.resolve(&request);
+        request.extensions_mut().insert(locale);
+        self.inner.call(request)
+    }
+}
@@ -0,0 +1,119 @@
+//! The `i18nrs` command line tool.
+//!
+//! ```sh
+//! i18nrs lint i18n/ --reference en --format sarif > i18n.sarif
+//! ```
+
+use i18nrs::lint::{count, lint_catalogs, read_catalogs, to_human, to_json, to_sarif};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: i18nrs lint <DIR> [OPTIONS]
+
+Lints the translation catalogs of DIR, laid out as DIR/<lang>/<namespace>.json or DIR/<lang>.json.
+Namespaces may be nested, as in DIR/<lang>/admin/users.json.
+
+Options:
+  -r, --reference <LANG>   Language the others are compared with [default: en]
+  -f, --format <FORMAT>    Output format: human, json or sarif [default: human]
+      --deny-warnings      Exit with a failure status on warnings too
+  -h, --help               Print this help
+
+Exit status: 0 without problems, 1 if problems were found, 2 on usage or read errors.";
+
+/// Enum representing the report formats.
+enum Format {
+    Human,
+    Json,
+    Sarif,
+}
+
+/// The options of the `lint` command.
+struct LintArgs {
+    dir: PathBuf,
+    reference: String,
+    format: Format,
+    deny_warnings: bool,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    match parse_args(&args).and_then(|args| lint(&args)) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Parses the command line arguments.
+fn parse_args(args: &[String]) -> Result<LintArgs, String> {
+    let mut args = args.iter();
+    match args.next().map(String::as_str) {
+        Some("lint") => {}
+        Some(command) => return Err(format!("unknown command '{}'", command)),
+        None => return Err("missing command".to_string()),
+    }
+
+    let mut dir = None;
+    let mut reference = "en".to_string();
+    let mut format = Format::Human;
+    let mut deny_warnings = false;
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("missing value for '{}'", arg))
+        };
+        match arg.as_str() {
+            "-r" | "--reference" => reference = value()?,
+            "-f" | "--format" => {
+                format = match value()?.as_str() {
+                    "human" => Format::Human,
+                    "json" => Format::Json,
+                    "sarif" => Format::Sarif,
+                    other => return Err(format!("unknown format '{}'", other)),
+                }
+            }
+            "--deny-warnings" => deny_warnings = true,
+            option if option.starts_with('-') => {
+                return Err(format!("unknown option '{}'", option))
+            }
+            path if dir.is_none() => dir = Some(PathBuf::from(path)),
+            path => return Err(format!("unexpected argument '{}'", path)),
+        }
+    }
+
+    Ok(LintArgs {
+        dir: dir.ok_or_else(|| "missing catalog directory".to_string())?,
+        reference,
+        format,
+        deny_warnings,
+    })
+}
+
+/// Lints the catalogs and prints the report.
+fn lint(args: &LintArgs) -> Result<ExitCode, String> {
+    let catalogs = read_catalogs(&args.dir)?;
+    let issues = lint_catalogs(&catalogs, &args.reference)?;
+
+    match args.format {
+        Format::Human => print!("{}", to_human(&issues)),
+        Format::Json => println!("{:#}", to_json(&issues)),
+        Format::Sarif => println!("{:#}", to_sarif(&issues)),
+    }
+
+    let (errors, warnings) = count(&issues);
+    if errors > 0 || (args.deny_warnings && warnings > 0) {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
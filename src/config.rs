@@ -1,30 +1,141 @@
-use gloo_storage::{LocalStorage, SessionStorage, Storage};
+use crate::cookie::CookieOptions;
+use crate::language::{region_flag, region_subtag, LanguageInfo, LanguageMetadata};
+use crate::missing::{MissKind, MissingKeyRecorder};
+use crate::storage::{CookieStore, LanguageStore, LocalStorageStore, SessionStorageStore};
 use serde_json::{self, Value};
 use std::collections::HashMap;
+use std::rc::Rc;
 
 /// Configuration for the I18n module, specifying supported languages and translations.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Build it with `I18nConfig::new` and its builder methods, or with a struct literal ending in
+/// `..Default::default()`, so that fields added in later releases do not break your code.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::{I18nConfig, LanguageMetadata};
+/// use std::collections::HashMap;
+///
+/// let config = I18nConfig::new(
+///     vec!["en", "fr"],
+///     HashMap::from([("en", r#"{}"#), ("fr", r#"{}"#)]),
+/// )
+/// .metadata(
+///     "fr",
+///     LanguageMetadata {
+///         flag: Some("🇨🇦"),
+///         ..LanguageMetadata::lookup("fr").unwrap()
+///     },
+/// );
+///
+/// assert_eq!(config.metadata["fr"].native_name, "français");
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct I18nConfig {
     /// List of supported languages in the application.
     /// Example: `vec!["en", "fr", "es"]`.
     pub languages: Vec<&'static str>,
     /// Mapping of language codes to raw JSON strings representing translation data.
     /// Example: `HashMap::from([("en", "{...}"), ("fr", "{...}")])`.
+    ///
+    /// It keeps the translations the instance was created with: `I18n::add_language`,
+    /// `I18n::remove_language` and `I18n::merge_translations` only change the parsed catalog.
     pub translations: HashMap<&'static str, &'static str>,
+    /// Per-language metadata overriding the built-in CLDR data.
+    /// Example: `HashMap::from([("fr", LanguageMetadata { flag: Some("🇨🇦"), ..LanguageMetadata::lookup("fr").unwrap() })])`.
+    pub metadata: HashMap<&'static str, LanguageMetadata>,
 }
 
-/// Enum representing browser storage options for persisting the selected language.
-#[derive(Debug, Clone, PartialEq, Default)]
+impl I18nConfig {
+    /// Creates a configuration with the given languages and translations, and no metadata overrides.
+    ///
+    /// # Arguments
+    /// - `languages`: The supported languages, the first one being the default language.
+    /// - `translations`: A `HashMap` containing language codes as keys and JSON strings as values.
+    pub fn new(
+        languages: Vec<&'static str>,
+        translations: HashMap<&'static str, &'static str>,
+    ) -> Self {
+        I18nConfig {
+            languages,
+            translations,
+            ..Default::default()
+        }
+    }
+
+    /// Overrides the built-in metadata of a language.
+    ///
+    /// # Arguments
+    /// - `code`: The language code (e.g., `"fr"`).
+    /// - `metadata`: The metadata to use, replacing any previous override.
+    pub fn metadata(mut self, code: &'static str, metadata: LanguageMetadata) -> Self {
+        self.metadata.insert(code, metadata);
+        self
+    }
+}
+
+/// Enum representing storage options for persisting the selected language.
+#[derive(Debug, Clone, Default)]
 pub enum StorageType {
     /// Use the browser's `LocalStorage` for persisting data.
     #[default]
     LocalStorage,
     /// Use the browser's `SessionStorage` for persisting data.
     SessionStorage,
+    /// Use a cookie, readable by the server, for persisting data.
+    /// The cookie is named after the storage name and carries the given attributes.
+    Cookie(CookieOptions),
+    /// Use a custom `LanguageStore` implementation (e.g., a user profile backend or IndexedDB).
+    Custom(Rc<dyn LanguageStore>),
+}
+
+impl PartialEq for StorageType {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (StorageType::LocalStorage, StorageType::LocalStorage)
+            | (StorageType::SessionStorage, StorageType::SessionStorage) => true,
+            (StorageType::Cookie(a), StorageType::Cookie(b)) => a == b,
+            (StorageType::Custom(a), StorageType::Custom(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl StorageType {
+    /// Builds the store behind this storage option.
+    ///
+    /// Resolve it once (e.g., when a provider is created) and keep the returned store, rather than
+    /// resolving the `StorageType` on every read or write.
+    ///
+    /// # Returns
+    /// - The `LanguageStore` to read and write the language with; `Custom` stores are shared, not copied.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use i18nrs::storage::{LanguageStore, MemoryStore};
+    /// use i18nrs::StorageType;
+    /// use std::rc::Rc;
+    ///
+    /// let memory = MemoryStore::default();
+    /// let store = StorageType::Custom(Rc::new(memory.clone())).resolve();
+    ///
+    /// store.write("i18nrs", "fr").unwrap();
+    /// assert_eq!(memory.read("i18nrs"), Some("fr".to_string()));
+    /// ```
+    pub fn resolve(&self) -> Rc<dyn LanguageStore> {
+        match self {
+            StorageType::LocalStorage => Rc::new(LocalStorageStore),
+            StorageType::SessionStorage => Rc::new(SessionStorageStore),
+            StorageType::Cookie(options) => Rc::new(CookieStore {
+                options: options.clone(),
+            }),
+            StorageType::Custom(store) => store.clone(),
+        }
+    }
 }
 
 /// This struct represents the state and methods for managing internationalization.
-#[derive(Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct I18n {
     /// Configuration for I18n, specifying supported languages and translations.
     pub config: I18nConfig,
@@ -33,6 +144,8 @@ pub struct I18n {
     /// Translations loaded for each supported language, represented as a mapping from
     /// language codes to JSON structures (`serde_json::Value`).
     translations: HashMap<String, Value>,
+    /// The opt-in recorder of missing and fallback lookups, shared by every clone.
+    recorder: Option<MissingKeyRecorder>,
 }
 
 impl I18n {
@@ -46,7 +159,7 @@ impl I18n {
     /// - `Ok(I18n)` if initialization is successful.
     /// - `Err(String)` if there is an error, such as missing translations or invalid JSON.
     pub fn new(config: I18nConfig, translations: HashMap<&str, &str>) -> Result<Self, String> {
-        let translations = Self::load_translations(&config.languages, translations)?;
+        let translations = Self::load_translations(&config.languages, &translations)?;
 
         let current_language = config
             .languages
@@ -58,9 +171,108 @@ impl I18n {
             config,
             current_language: current_language.to_string(),
             translations,
+            recorder: None,
         })
     }
 
+    /// Initializes an `I18n` instance with the languages whose translations load, skipping the others.
+    ///
+    /// Unlike `new`, a missing or malformed translation only removes its language from
+    /// `config.languages`, so an application can keep running with the languages that loaded.
+    ///
+    /// # Arguments
+    /// - `config`: The `I18nConfig` containing supported languages and the translations map.
+    /// - `translations`: A `HashMap` containing language codes as keys and JSON strings as values.
+    ///
+    /// # Returns
+    /// - `Ok((I18n, Vec<String>))` with the instance and one error message per skipped language.
+    /// - `Err(Vec<String>)` with the error messages if no language loaded.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([
+    ///     ("en", r#"{"greeting": "Hello"}"#),
+    ///     ("fr", r#"{"greeting": "Bonjour""#),
+    /// ]);
+    /// let (i18n, errors) = I18n::new_lenient(
+    ///     I18nConfig {
+    ///         languages: vec!["en", "fr"],
+    ///         translations: translations.clone(),
+    ///         ..Default::default()
+    ///     },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(i18n.config.languages, ["en"]);
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    ///
+    /// When the first language fails, the first language that loads becomes the default language:
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([
+    ///     ("en", r#"{"greeting": "#),
+    ///     ("fr", r#"{"greeting": "Bonjour"}"#),
+    ///     ("de", r#"{"greeting": "Hallo"}"#),
+    /// ]);
+    /// let (i18n, errors) = I18n::new_lenient(
+    ///     I18nConfig {
+    ///         languages: vec!["en", "fr", "de"],
+    ///         translations: translations.clone(),
+    ///         ..Default::default()
+    ///     },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(i18n.config.languages, ["fr", "de"]);
+    /// assert_eq!(i18n.get_current_language(), "fr");
+    /// assert_eq!(i18n.t("greeting"), "Bonjour");
+    /// assert!(errors[0].contains("en"));
+    /// ```
+    pub fn new_lenient(
+        mut config: I18nConfig,
+        translations: HashMap<&str, &str>,
+    ) -> Result<(Self, Vec<String>), Vec<String>> {
+        let mut loaded_translations = HashMap::new();
+        let mut errors = Vec::new();
+        config.languages.retain(|&language| {
+            match Self::load_translations(&[language], &translations) {
+                Ok(loaded) => {
+                    loaded_translations.extend(loaded);
+                    true
+                }
+                Err(err) => {
+                    errors.push(err);
+                    false
+                }
+            }
+        });
+
+        let Some(current_language) = config
+            .languages
+            .first()
+            .map(|language| language.to_string())
+        else {
+            errors.push("You must add at least one supported language".to_string());
+            return Err(errors);
+        };
+
+        let i18n = I18n {
+            config,
+            current_language,
+            translations: loaded_translations,
+            recorder: None,
+        };
+        Ok((i18n, errors))
+    }
+
     /// Loads translations for the given languages from a `HashMap` of raw JSON strings.
     ///
     /// # Arguments
@@ -72,7 +284,7 @@ impl I18n {
     /// - `Err(String)` if any translation is missing or invalid.
     fn load_translations(
         languages: &[&str],
-        translations: HashMap<&str, &str>,
+        translations: &HashMap<&str, &str>,
     ) -> Result<HashMap<String, Value>, String> {
         let mut loaded_translations = HashMap::new();
 
@@ -89,11 +301,75 @@ impl I18n {
         Ok(loaded_translations)
     }
 
+    /// Sets the translation language without persisting it.
+    ///
+    /// Use this to preview a language or to switch languages where no browser storage is available
+    /// (e.g., in tests or on native targets). Call `persist_language` to store the choice.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to set (e.g., `"en"`).
+    ///
+    /// # Returns
+    /// - `Ok(())` if the language was successfully set.
+    /// - `Err(String)` if the language is not supported.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([
+    ///     ("en", r#"{"greeting": "Hello"}"#),
+    ///     ("fr", r#"{"greeting": "Bonjour"}"#),
+    /// ]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig {
+    ///         languages: vec!["en", "fr"],
+    ///         translations: translations.clone(),
+    ///         ..Default::default()
+    ///     },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// i18n.set_language("fr").unwrap();
+    /// assert_eq!(i18n.t("greeting"), "Bonjour");
+    /// assert!(i18n.set_language("de").is_err());
+    /// ```
+    pub fn set_language(&mut self, language: &str) -> Result<(), String> {
+        if self.config.languages.contains(&language) {
+            self.current_language = language.to_string();
+            Ok(())
+        } else {
+            Err(format!("Language '{}' is not supported", language))
+        }
+    }
+
+    /// Persists the current language in a store.
+    ///
+    /// # Arguments
+    /// - `store`: The store to write to (e.g., a resolved `StorageType` or a `MemoryStore`).
+    /// - `storage_name`: The key to use for storing the selected language.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the language was successfully stored.
+    /// - `Err(String)` if the store fails.
+    pub fn persist_language(
+        &self,
+        store: &dyn LanguageStore,
+        storage_name: &str,
+    ) -> Result<(), String> {
+        store.write(storage_name, &self.current_language)
+    }
+
     /// Sets the translation language and stores it in the browser's storage.
     ///
+    /// This is a shorthand for `set_language` followed by `persist_language`.
+    ///
     /// # Arguments
     /// - `language`: The language code to set (e.g., `"en"`).
-    /// - `storage_type`: The type of browser storage to use (`StorageType::LocalStorage` or `StorageType::SessionStorage`).
+    /// - `storage_type`: The storage to use (`StorageType::LocalStorage`, `StorageType::SessionStorage`,
+    ///   `StorageType::Cookie` or a `StorageType::Custom` store).
     /// - `storage_name`: The key to use for storing the selected language.
     ///
     /// # Returns
@@ -105,17 +381,122 @@ impl I18n {
         storage_type: &StorageType,
         storage_name: &str,
     ) -> Result<(), String> {
+        self.set_language(language)?;
+        self.persist_language(&*storage_type.resolve(), storage_name)
+    }
+
+    /// Adds a supported language with its translations.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to add (e.g., `"de"`).
+    /// - `json`: The translations of the language, as a JSON string.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the language was added.
+    /// - `Err(String)` if the language is already supported or the JSON is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"menu": {"open": "Open", "save": "Save"}}"#)]);
+    /// let mut i18n = I18n::new(
+    ///     I18nConfig {
+    ///         languages: vec!["en"],
+    ///         translations: translations.clone(),
+    ///         ..Default::default()
+    ///     },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// i18n.add_language("de", r#"{"menu": {"open": "Öffnen"}}"#).unwrap();
+    /// i18n.merge_translations("de", r#"{"menu": {"save": "Speichern"}}"#).unwrap();
+    /// i18n.set_language("de").unwrap();
+    /// assert_eq!(i18n.t("menu.open"), "Öffnen");
+    /// assert_eq!(i18n.t("menu.save"), "Speichern");
+    ///
+    /// i18n.remove_language("de").unwrap();
+    /// assert_eq!(i18n.get_current_language(), "en");
+    /// ```
+    pub fn add_language(&mut self, language: &'static str, json: &str) -> Result<(), String> {
         if self.config.languages.contains(&language) {
-            self.current_language = language.to_string();
-            match storage_type {
-                StorageType::LocalStorage => LocalStorage::set(storage_name, language)
-                    .map_err(|_| "Failed to write to LocalStorage".to_string())?,
-                StorageType::SessionStorage => SessionStorage::set(storage_name, language)
-                    .map_err(|_| "Failed to write to SessionStorage".to_string())?,
+            return Err(format!("Language '{}' is already supported", language));
+        }
+
+        let json: Value = serde_json::from_str(json)
+            .map_err(|err| format!("Invalid JSON for language {}: {}", language, err))?;
+        self.config.languages.push(language);
+        self.translations.insert(language.to_string(), json);
+        Ok(())
+    }
+
+    /// Removes a supported language and its translations.
+    ///
+    /// If it was the current language, the first remaining language becomes current.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to remove (e.g., `"de"`).
+    ///
+    /// # Returns
+    /// - `Ok(())` if the language was removed.
+    /// - `Err(String)` if the language is not supported or is the only one left.
+    pub fn remove_language(&mut self, language: &str) -> Result<(), String> {
+        if !self.config.languages.contains(&language) {
+            return Err(format!("Language '{}' is not supported", language));
+        }
+        if self.config.languages.len() == 1 {
+            return Err("You must keep at least one supported language".to_string());
+        }
+
+        self.config
+            .languages
+            .retain(|supported| *supported != language);
+        self.translations.remove(language);
+        if self.current_language == language {
+            self.current_language = self.config.languages[0].to_string();
+        }
+        Ok(())
+    }
+
+    /// Deep-merges additional translations into a supported language.
+    ///
+    /// Nested objects are merged key by key; any other value replaces the existing one.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to extend (e.g., `"fr"`).
+    /// - `json`: The translations to merge, as a JSON string.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the translations were merged.
+    /// - `Err(String)` if the language is not supported or the JSON is invalid.
+    pub fn merge_translations(&mut self, language: &str, json: &str) -> Result<(), String> {
+        let additions: Value = serde_json::from_str(json)
+            .map_err(|err| format!("Invalid JSON for language {}: {}", language, err))?;
+        let catalog = self
+            .translations
+            .get_mut(language)
+            .ok_or_else(|| format!("Language '{}' is not supported", language))?;
+
+        Self::merge_values(catalog, additions);
+        Ok(())
+    }
+
+    /// Recursively merges `source` into `target`.
+    fn merge_values(target: &mut Value, source: Value) {
+        match (target, source) {
+            (Value::Object(target), Value::Object(source)) => {
+                for (key, value) in source {
+                    match target.get_mut(&key) {
+                        Some(existing) => Self::merge_values(existing, value),
+                        None => {
+                            target.insert(key, value);
+                        }
+                    }
+                }
             }
-            Ok(())
-        } else {
-            Err(format!("Language '{}' is not supported", language))
+            (target, source) => *target = source,
         }
     }
 
@@ -136,23 +517,42 @@ impl I18n {
     /// - The translated string if the key exists.
     /// - A fallback message if the key or translation does not exist.
     pub fn t(&self, key: &str) -> String {
+        self.translate(&self.current_language, key)
+    }
+
+    /// Translates a given key using the given language, regardless of the current language.
+    ///
+    /// This lets a single shared instance serve several languages at once (e.g., one per server request).
+    ///
+    /// # Arguments
+    /// - `language`: The language code to translate into (e.g., `"fr"`).
+    /// - `key`: The translation key to retrieve (e.g., `"menu.file.open"`).
+    ///
+    /// # Returns
+    /// - The translated string if the key exists in `language` or in the default language.
+    /// - A fallback message if the key or translation does not exist.
+    pub fn translate(&self, language: &str, key: &str) -> String {
         let keys: Vec<&str> = key.split('.').collect();
 
         self.translations
-            .get(&self.current_language)
+            .get(language)
             .and_then(|language_json| Self::get_nested_value(language_json, &keys))
             .or_else(|| {
-                self.translations
+                let fallback = self
+                    .translations
                     .get(self.config.languages[0])
-                    .and_then(|default_json| Self::get_nested_value(default_json, &keys))
+                    .and_then(|default_json| Self::get_nested_value(default_json, &keys));
+                if let Some(recorder) = &self.recorder {
+                    let kind = match fallback {
+                        Some(_) => MissKind::Fallback,
+                        None => MissKind::Missing,
+                    };
+                    recorder.record(language, key, kind);
+                }
+                fallback
             })
             .map_or_else(
-                || {
-                    format!(
-                        "Key '{}' not found for language '{}'",
-                        key, self.current_language
-                    )
-                },
+                || format!("Key '{}' not found for language '{}'", key, language),
                 |value| match value {
                     Value::String(s) => s.clone(),
                     _ => value.to_string(),
@@ -160,6 +560,33 @@ impl I18n {
             )
     }
 
+    /// Records missing keys and fallback-language hits in the given recorder.
+    ///
+    /// Recording is opt-in, as it costs a lock on every missed lookup. See `MissingKeyRecorder`.
+    ///
+    /// # Arguments
+    /// - `recorder`: The recorder to use, or `None` to stop recording.
+    pub fn record_missing(&mut self, recorder: Option<MissingKeyRecorder>) {
+        self.recorder = recorder;
+    }
+
+    /// Retrieves the recorder of missing keys, if recording is enabled.
+    pub fn missing_recorder(&self) -> Option<&MissingKeyRecorder> {
+        self.recorder.as_ref()
+    }
+
+    /// Retrieves the parsed translations of a language.
+    ///
+    /// # Arguments
+    /// - `language`: The language code (e.g., `"fr"`).
+    ///
+    /// # Returns
+    /// - `Some(&Value)` if the language is loaded.
+    /// - `None` otherwise.
+    pub(crate) fn catalog(&self, language: &str) -> Option<&Value> {
+        self.translations.get(language)
+    }
+
     /// Retrieves a nested value from a JSON object using a sequence of keys.
     ///
     /// # Arguments
@@ -172,4 +599,122 @@ impl I18n {
     fn get_nested_value<'a>(json: &'a Value, keys: &[&str]) -> Option<&'a Value> {
         keys.iter().try_fold(json, |current, key| current.get(key))
     }
+
+    /// Retrieves the metadata of a language, resolved for the current language.
+    ///
+    /// Metadata comes from `I18nConfig::metadata` when provided, otherwise from the built-in CLDR data.
+    /// The flag emoji is, in order, the one set in `I18nConfig::metadata`, the one of the region subtag
+    /// in the code (e.g., `"pt-BR"`), then the built-in one.
+    ///
+    /// The localized name is resolved in this order:
+    /// 1. The `languages.<code>` key of the current language's translations.
+    /// 2. The native name, if `code` is the current language.
+    /// 3. The English name.
+    ///
+    /// # Arguments
+    /// - `code`: The language code to describe (e.g., `"fr"`).
+    ///
+    /// # Returns
+    /// - A `LanguageInfo`; unknown languages use `code` for every name.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig, TextDirection};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([
+    ///     ("en", r#"{"languages": {"ar": "Arabic (Egypt)"}}"#),
+    ///     ("ar", r#"{}"#),
+    /// ]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig {
+    ///         languages: vec!["en", "ar"],
+    ///         translations: translations.clone(),
+    ///         ..Default::default()
+    ///     },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// let arabic = i18n.language_info("ar");
+    /// assert_eq!(arabic.native_name, "العربية");
+    /// assert_eq!(arabic.localized_name, "Arabic (Egypt)");
+    /// assert_eq!(arabic.direction, TextDirection::Rtl);
+    /// assert_eq!(i18n.language_info("pt-PT").flag.as_deref(), Some("🇵🇹"));
+    /// ```
+    ///
+    /// An explicit flag wins over the region subtag:
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig, LanguageMetadata};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("pt-BR", r#"{}"#)]);
+    /// let config = I18nConfig::new(vec!["pt-BR"], translations.clone()).metadata(
+    ///     "pt-BR",
+    ///     LanguageMetadata {
+    ///         flag: Some("🏳️"),
+    ///         ..LanguageMetadata::lookup("pt").unwrap()
+    ///     },
+    /// );
+    /// let i18n = I18n::new(config, translations).unwrap();
+    ///
+    /// assert_eq!(i18n.language_info("pt-BR").flag.as_deref(), Some("🏳️"));
+    /// ```
+    pub fn language_info(&self, code: &str) -> LanguageInfo {
+        let explicit = self.config.metadata.get(code).cloned();
+        let explicit_flag = explicit.as_ref().and_then(|metadata| metadata.flag);
+        let metadata = explicit
+            .or_else(|| LanguageMetadata::lookup(code))
+            .unwrap_or_default();
+
+        let native_name = match metadata.native_name {
+            "" => code.to_string(),
+            name => name.to_string(),
+        };
+        let english_name = match metadata.english_name {
+            "" => code.to_string(),
+            name => name.to_string(),
+        };
+
+        let localized_name = self
+            .translations
+            .get(&self.current_language)
+            .and_then(|json| Self::get_nested_value(json, &["languages", code]))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| {
+                if self.current_language == code {
+                    native_name.clone()
+                } else {
+                    english_name.clone()
+                }
+            });
+
+        let flag = explicit_flag
+            .map(str::to_string)
+            .or_else(|| region_subtag(code).and_then(region_flag))
+            .or_else(|| metadata.flag.map(str::to_string));
+
+        LanguageInfo {
+            code: code.to_string(),
+            native_name,
+            english_name,
+            localized_name,
+            script: metadata.script.to_string(),
+            direction: metadata.direction,
+            flag,
+        }
+    }
+
+    /// Retrieves the metadata of every supported language, in configuration order.
+    ///
+    /// # Returns
+    /// - A `Vec<LanguageInfo>` with one entry per language in `config.languages`.
+    pub fn languages_info(&self) -> Vec<LanguageInfo> {
+        self.config
+            .languages
+            .iter()
+            .map(|language| self.language_info(language))
+            .collect()
+    }
 }
@@ -0,0 +1,162 @@
+#[cfg(feature = "browser")]
+use wasm_bindgen::JsCast;
+#[cfg(feature = "browser")]
+use web_sys::HtmlDocument;
+
+/// Enum representing the `SameSite` attribute of a cookie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SameSite {
+    /// The cookie is only sent with same-site requests.
+    Strict,
+    /// The cookie is also sent on top-level navigations from other sites.
+    #[default]
+    Lax,
+    /// The cookie is sent with all requests. Browsers require `Secure` along with it.
+    None,
+}
+
+impl SameSite {
+    /// Returns the attribute value (`"Strict"`, `"Lax"` or `"None"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SameSite::Strict => "Strict",
+            SameSite::Lax => "Lax",
+            SameSite::None => "None",
+        }
+    }
+}
+
+/// Attributes of the cookie persisting the selected language.
+///
+/// The cookie itself is named after the storage name (`"i18nrs"` by default).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CookieOptions {
+    /// The `Path` attribute. Defaults to `Some("/")` so the whole site shares the language.
+    pub path: Option<String>,
+    /// The `Domain` attribute. Defaults to `None` (the current host only).
+    pub domain: Option<String>,
+    /// The `Max-Age` attribute, in seconds. Defaults to one year; `None` makes a session cookie.
+    pub max_age: Option<u64>,
+    /// The `SameSite` attribute. Defaults to `Some(SameSite::Lax)`.
+    pub same_site: Option<SameSite>,
+    /// Whether to set the `Secure` attribute. Defaults to `false`.
+    pub secure: bool,
+}
+
+impl Default for CookieOptions {
+    fn default() -> Self {
+        CookieOptions {
+            path: Some("/".to_string()),
+            domain: None,
+            max_age: Some(60 * 60 * 24 * 365),
+            same_site: Some(SameSite::Lax),
+            secure: false,
+        }
+    }
+}
+
+impl CookieOptions {
+    /// Serializes a cookie with these attributes, as expected by `document.cookie` or a `Set-Cookie` header.
+    ///
+    /// # Arguments
+    /// - `name`: The cookie name.
+    /// - `value`: The cookie value (e.g., a language code).
+    ///
+    /// # Returns
+    /// - The serialized cookie (e.g., `"i18nrs=fr; Path=/; Max-Age=31536000; SameSite=Lax"`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use i18nrs::cookie::{CookieOptions, SameSite};
+    ///
+    /// let options = CookieOptions {
+    ///     domain: Some("example.com".to_string()),
+    ///     max_age: None,
+    ///     same_site: Some(SameSite::Strict),
+    ///     secure: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     options.to_cookie_string("i18nrs", "fr"),
+    ///     "i18nrs=fr; Path=/; Domain=example.com; SameSite=Strict; Secure"
+    /// );
+    /// ```
+    pub fn to_cookie_string(&self, name: &str, value: &str) -> String {
+        let mut cookie = format!("{}={}", name, value);
+
+        if let Some(path) = &self.path {
+            cookie.push_str(&format!("; Path={}", path));
+        }
+        if let Some(domain) = &self.domain {
+            cookie.push_str(&format!("; Domain={}", domain));
+        }
+        if let Some(max_age) = self.max_age {
+            cookie.push_str(&format!("; Max-Age={}", max_age));
+        }
+        if let Some(same_site) = self.same_site {
+            cookie.push_str(&format!("; SameSite={}", same_site.as_str()));
+        }
+        if self.secure {
+            cookie.push_str("; Secure");
+        }
+
+        cookie
+    }
+}
+
+/// Reads a cookie value out of a `Cookie` header or `document.cookie` string.
+///
+/// # Arguments
+/// - `cookies`: The cookie string (e.g., `"theme=dark; i18nrs=fr"`).
+/// - `name`: The cookie name.
+///
+/// # Returns
+/// - `Some(String)` with the cookie value, or `None` if the cookie is absent or empty.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::cookie::parse_cookie;
+///
+/// assert_eq!(parse_cookie("theme=dark; i18nrs=fr", "i18nrs"), Some("fr".to_string()));
+/// assert_eq!(parse_cookie("theme=dark", "i18nrs"), None);
+/// ```
+pub fn parse_cookie(cookies: &str, name: &str) -> Option<String> {
+    cookies
+        .split(';')
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.trim_matches('"').to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Reads a cookie from `document.cookie`.
+///
+/// # Returns
+/// - `Some(String)` with the cookie value, or `None` if it is absent or outside a browser.
+#[cfg(feature = "browser")]
+pub(crate) fn read_cookie(name: &str) -> Option<String> {
+    let cookies = html_document()?.cookie().ok()?;
+    parse_cookie(&cookies, name)
+}
+
+/// Writes a cookie through `document.cookie`.
+///
+/// # Returns
+/// - `Err(String)` if the document is unavailable or rejects the cookie.
+#[cfg(feature = "browser")]
+pub(crate) fn write_cookie(name: &str, value: &str, options: &CookieOptions) -> Result<(), String> {
+    html_document()
+        .ok_or_else(|| "Failed to access the document cookies".to_string())?
+        .set_cookie(&options.to_cookie_string(name, value))
+        .map_err(|_| "Failed to write the language cookie".to_string())
+}
+
+/// Retrieves the current document as an `HtmlDocument`.
+#[cfg(feature = "browser")]
+fn html_document() -> Option<HtmlDocument> {
+    web_sys::window()?
+        .document()?
+        .dyn_into::<HtmlDocument>()
+        .ok()
+}
@@ -0,0 +1,191 @@
+use crate::negotiate::{match_language, negotiate_language};
+use crate::storage::LanguageStore;
+
+/// The attribute of the element embedding the server-rendered language in the page.
+pub const SSR_LANGUAGE_ATTRIBUTE: &str = "data-i18nrs-language";
+
+/// Enum representing the sources consulted to pick the initial language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LanguageSource {
+    /// The language previously persisted in browser storage.
+    Storage,
+    /// The language found in the page URL, either as a query parameter (`?lang=fr`)
+    /// or as the first path segment (`/fr/pricing`).
+    Url,
+    /// The user's preferred languages reported by the browser (`navigator.languages`).
+    Navigator,
+    /// The configured default language.
+    Default,
+}
+
+/// Options for the language detection pipeline.
+#[derive(Debug, Clone, Copy)]
+pub struct DetectionOptions<'a> {
+    /// The sources to consult, in order. The first one yielding a supported language wins.
+    pub sources: &'a [LanguageSource],
+    /// The supported language codes.
+    pub languages: &'a [&'static str],
+    /// The store holding the persisted language (e.g., a resolved `StorageType`).
+    pub store: &'a dyn LanguageStore,
+    /// The key of the persisted language in browser storage.
+    pub storage_name: &'a str,
+    /// The name of the URL query parameter holding the language (e.g., `"lang"`).
+    pub query_param: &'a str,
+    /// The language returned by `LanguageSource::Default`.
+    pub default_language: &'a str,
+}
+
+/// Runs the language detection pipeline.
+///
+/// Each source is consulted in order and its candidates are negotiated against the supported
+/// languages with BCP 47 matching. If no source yields a supported language, the default language
+/// is returned.
+///
+/// # Arguments
+/// - `options`: The `DetectionOptions` describing the sources and supported languages.
+///
+/// # Returns
+/// - The detected language code.
+pub fn detect_language(options: &DetectionOptions) -> String {
+    detect_language_with_source(options).0
+}
+
+/// Runs the language detection pipeline, reporting which source the language came from.
+///
+/// # Arguments
+/// - `options`: The `DetectionOptions` describing the sources and supported languages.
+///
+/// # Returns
+/// - The detected language code, and the source that yielded it (`LanguageSource::Default` if none did).
+pub fn detect_language_with_source(options: &DetectionOptions) -> (String, LanguageSource) {
+    options
+        .sources
+        .iter()
+        .find_map(|source| {
+            let language = match source {
+                LanguageSource::Storage => options
+                    .store
+                    .read(options.storage_name)
+                    .and_then(|language| match_language(&language, options.languages))
+                    .map(str::to_string),
+                LanguageSource::Url => url_language(options.query_param, options.languages),
+                LanguageSource::Navigator => {
+                    negotiate_language(&navigator_languages(), options.languages)
+                        .map(str::to_string)
+                }
+                LanguageSource::Default => Some(options.default_language.to_string()),
+            };
+            language.map(|language| (language, *source))
+        })
+        .unwrap_or_else(|| {
+            (
+                options.default_language.to_string(),
+                LanguageSource::Default,
+            )
+        })
+}
+
+/// Reads the user's preferred languages from `navigator.languages`, falling back to `navigator.language`.
+///
+/// # Returns
+/// - The language tags in preference order, or an empty `Vec` outside a browser.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::detect::navigator_languages;
+///
+/// // Doctests run natively, where there is no `navigator`.
+/// assert!(navigator_languages().is_empty());
+/// ```
+#[cfg(feature = "browser")]
+pub fn navigator_languages() -> Vec<String> {
+    if !cfg!(target_arch = "wasm32") {
+        return Vec::new();
+    }
+    let Some(navigator) = web_sys::window().map(|window| window.navigator()) else {
+        return Vec::new();
+    };
+
+    let languages: Vec<String> = navigator
+        .languages()
+        .iter()
+        .filter_map(|language| language.as_string())
+        .collect();
+
+    if languages.is_empty() {
+        navigator.language().into_iter().collect()
+    } else {
+        languages
+    }
+}
+
+/// Reads a supported language from the current page URL.
+///
+/// The query parameter is checked first (`?lang=fr`), then the first path segment (`/fr/pricing`).
+///
+/// # Arguments
+/// - `query_param`: The name of the query parameter holding the language.
+/// - `languages`: The supported language codes.
+///
+/// # Returns
+/// - `Some(String)` with the matching supported language, or `None`.
+#[cfg(feature = "browser")]
+pub fn url_language(query_param: &str, languages: &[&'static str]) -> Option<String> {
+    if !cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    let location = web_sys::window()?.location();
+
+    let search = location.search().unwrap_or_default();
+    let from_query = search
+        .trim_start_matches('?')
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(name, _)| *name == query_param)
+        .and_then(|(_, value)| match_language(value, languages));
+
+    from_query
+        .or_else(|| {
+            let pathname = location.pathname().unwrap_or_default();
+            let segment = pathname.trim_start_matches('/').split('/').next()?;
+            languages
+                .iter()
+                .copied()
+                .find(|language| language.eq_ignore_ascii_case(segment))
+        })
+        .map(str::to_string)
+}
+
+/// Reads the language embedded by a server-rendered page (see `SSR_LANGUAGE_ATTRIBUTE`).
+///
+/// # Returns
+/// - `Some(String)` with the embedded language, or `None` if the page was not server-rendered.
+#[cfg(feature = "browser")]
+pub fn embedded_language() -> Option<String> {
+    if !cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    web_sys::window()?
+        .document()?
+        .query_selector(&format!("[{}]", SSR_LANGUAGE_ATTRIBUTE))
+        .ok()??
+        .get_attribute(SSR_LANGUAGE_ATTRIBUTE)
+}
+
+/// Reads the language embedded by a server-rendered page; always `None` without the `browser` feature.
+#[cfg(not(feature = "browser"))]
+pub fn embedded_language() -> Option<String> {
+    None
+}
+
+/// Reads the user's preferred languages; always empty without the `browser` feature.
+#[cfg(not(feature = "browser"))]
+pub fn navigator_languages() -> Vec<String> {
+    Vec::new()
+}
+
+/// Reads a supported language from the current page URL; always `None` without the `browser` feature.
+#[cfg(not(feature = "browser"))]
+pub fn url_language(_query_param: &str, _languages: &[&'static str]) -> Option<String> {
+    None
+}
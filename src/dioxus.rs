@@ -0,0 +1,369 @@
+#![doc = include_str!("../DIOXUS.md")]
+
+use crate::config::{I18n, I18nConfig, StorageType};
+use crate::detect::{embedded_language, LanguageSource};
+use crate::language::LanguageMetadata;
+use crate::store::{I18nStore, StoreEvent};
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// The context provided by `I18nProvider` to its descendants.
+///
+/// Retrieve it with `use_context::<I18nContext>()`, or through `use_translation`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct I18nContext {
+    /// The current i18n state. Reading it subscribes the component to language changes.
+    pub i18n: Signal<I18n>,
+    /// Callback switching to the given language code, persisting it and emitting `onchange`.
+    pub set_language: Callback<String>,
+    /// The errors reported so far, including the languages that failed to load.
+    pub errors: Signal<Vec<String>>,
+}
+
+/// Properties for the `I18nProvider` component.
+///
+/// This configuration struct allows you to specify supported languages, translation paths, storage options,
+/// and callbacks for language change or error handling.
+#[derive(Props, Clone, PartialEq)]
+pub struct I18nProviderProps {
+    /// List of supported languages.
+    ///
+    /// Defaults to `["en", "fr"]` if not specified.
+    #[props(default = vec!["en", "fr"])]
+    pub languages: Vec<&'static str>,
+
+    /// The translations raw content.
+    ///
+    /// Specifies the mapping of language codes to file contents. Defaults to an empty `HashMap`.
+    #[props(default)]
+    pub translations: HashMap<&'static str, &'static str>,
+
+    /// Per-language metadata overrides of the built-in CLDR metadata. Defaults to an empty `HashMap`.
+    #[props(default)]
+    pub metadata: HashMap<&'static str, LanguageMetadata>,
+
+    /// The child elements to be wrapped with the `I18n` context.
+    pub children: Element,
+
+    /// The type of browser storage to use (`LocalStorage`, `SessionStorage`, `Cookie` or `Custom`).
+    ///
+    /// Defaults to `StorageType::LocalStorage`.
+    #[props(default)]
+    pub storage_type: StorageType,
+
+    /// The key for storing the selected language.
+    ///
+    /// Defaults to `"i18nrs"`.
+    #[props(into, default = "i18nrs".to_string())]
+    pub storage_name: String,
+
+    /// Whether to persist the selected language in `storage_type`.
+    ///
+    /// Defaults to `true`.
+    #[props(default = true)]
+    pub persist: bool,
+
+    /// Default language if no language is found in storage.
+    ///
+    /// Defaults to `"en"`.
+    #[props(into, default = "en".to_string())]
+    pub default_language: String,
+
+    /// Sources consulted, in order, to pick the initial language.
+    ///
+    /// Defaults to `[Storage, Default]`, so browser detection is opt-in.
+    #[props(default = vec![LanguageSource::Storage, LanguageSource::Default])]
+    pub detection: Vec<LanguageSource>,
+
+    /// The language resolved by the server for server-side rendering.
+    ///
+    /// When set, the server render uses it without touching browser APIs, and embeds it in the markup
+    /// so the client hydrates with the same language before applying `detection`.
+    ///
+    /// Defaults to `None`.
+    #[props(default)]
+    pub initial_language: Option<String>,
+
+    /// The URL query parameter read by `LanguageSource::Url`.
+    ///
+    /// Defaults to `"lang"`.
+    #[props(into, default = "lang".to_string())]
+    pub query_param: String,
+
+    /// Whether to apply language changes made in other tabs, through `storage` events on `storage_name`.
+    ///
+    /// Defaults to `true`.
+    #[props(default = true)]
+    pub sync_tabs: bool,
+
+    /// The name of a `BroadcastChannel` used to exchange language changes between tabs, for storages
+    /// without `storage` events (e.g., cookies). Ignored when `sync_tabs` is `false`.
+    ///
+    /// Defaults to `None`.
+    #[props(default)]
+    pub sync_channel: Option<String>,
+
+    /// Handler called with the new language code whenever the language changes.
+    #[props(default)]
+    pub onchange: EventHandler<String>,
+
+    /// Handler called with an error message whenever an error occurs in the internationalization process.
+    #[props(default)]
+    pub onerror: EventHandler<String>,
+
+    /// Rendered instead of the children if no language could be loaded.
+    ///
+    /// Defaults to an empty element.
+    #[props(default = VNode::empty())]
+    pub fallback: Element,
+}
+
+/// I18nProvider Component
+///
+/// A Dioxus component that provides internationalization (i18n) context to its child elements.
+/// The `I18n` state lives in a signal, so components reading it through `use_translation` re-render
+/// when the language changes.
+///
+/// # Properties
+/// The component uses the `I18nProviderProps` struct for its properties, mirroring the Yew `I18nProvider`:
+/// `languages`, `translations`, `metadata`, `storage_type`, `storage_name`, `persist`, `default_language`,
+/// `detection`, `initial_language`, `query_param`, `sync_tabs`, `sync_channel`, `onchange`, `onerror`
+/// and `fallback`.
+///
+/// # Examples
+/// ```rust
+/// use dioxus::prelude::*;
+/// use i18nrs::dioxus::{use_translation, I18nProvider};
+/// use std::collections::HashMap;
+///
+/// #[component]
+/// fn App(language: String) -> Element {
+///     let translations = HashMap::from([
+///         ("en", r#"{"greeting": "Hello"}"#),
+///         ("fr", r#"{"greeting": "Bonjour"}"#),
+///     ]);
+///
+///     rsx! {
+///         I18nProvider {
+///             languages: vec!["en", "fr"],
+///             translations: translations,
+///             initial_language: Some(language),
+///             onchange: move |language: String| log::info!("Language changed to: {}", language),
+///             Greeting {}
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn Greeting() -> Element {
+///     let (i18n, _set_language) = use_translation();
+///     rsx! { h1 { {i18n.read().t("greeting")} } }
+/// }
+///
+/// let mut dom = VirtualDom::new_with_props(App, AppProps { language: "fr".to_string() });
+/// dom.rebuild_in_place();
+/// let rendered = dioxus_ssr::render(&dom);
+///
+/// assert!(rendered.contains("Bonjour"));
+/// assert!(rendered.contains(r#"data-i18nrs-language="fr""#));
+/// ```
+///
+/// # Behavior
+/// - In the browser, picks the initial language by consulting the `detection` sources in order, and
+///   persists it in `storage_type` unless `persist` is `false`.
+/// - Outside the browser (i.e., not on `wasm32`), no browser API is used: the language is `initial_language`,
+///   or `default_language` if unset.
+/// - Calls `onchange` with the new language code after each switch, and `onerror` on initialization
+///   or persistence errors.
+/// - Never panics on bad translations: each language that fails to load is reported through `onerror`
+///   and left out, and `fallback` is rendered if no language loads at all.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use i18nrs::dioxus::I18nProvider;
+/// use std::collections::HashMap;
+///
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         I18nProvider {
+///             languages: vec!["en"],
+///             translations: HashMap::from([("en", r#"{"greeting": "#)]),
+///             onerror: move |err: String| log::error!("{}", err),
+///             fallback: rsx! { p { "Translations are unavailable" } },
+///             h1 { "Unreachable" }
+///         }
+///     }
+/// }
+///
+/// let mut dom = VirtualDom::new(App);
+/// dom.rebuild_in_place();
+///
+/// assert_eq!(dioxus_ssr::render(&dom), "<p>Translations are unavailable</p>");
+/// ```
+#[component]
+pub fn I18nProvider(props: I18nProviderProps) -> Element {
+    let loaded = use_hook(|| {
+        let loaded = I18n::new_lenient(
+            I18nConfig {
+                languages: props.languages.clone(),
+                translations: props.translations.clone(),
+                metadata: props.metadata.clone(),
+            },
+            props.translations.clone(),
+        );
+        let (Ok((_, errors)) | Err(errors)) = &loaded;
+        for err in errors {
+            props.onerror.call(err.clone());
+        }
+        loaded.ok()
+    });
+
+    match loaded {
+        Some((i18n, errors)) => rsx! {
+            LoadedProvider { i18n, errors, config: props }
+        },
+        None => props.fallback,
+    }
+}
+
+/// The body of an `I18nProvider` whose catalog loaded, re-rendering on the changes of its store.
+#[component]
+fn LoadedProvider(i18n: I18n, errors: Vec<String>, config: I18nProviderProps) -> Element {
+    let props = config;
+    let errors = use_signal(|| errors);
+    let onerror = props.onerror;
+    let report = move |err: String| {
+        let mut errors = errors;
+        errors.push(err.clone());
+        onerror.call(err);
+    };
+
+    let embedded = use_hook(|| {
+        if cfg!(target_arch = "wasm32") {
+            embedded_language()
+        } else {
+            None
+        }
+    });
+
+    let store = use_hook(|| {
+        let mut i18n = i18n;
+        let known_language = match (&embedded, cfg!(target_arch = "wasm32")) {
+            (Some(language), _) => Some(language.clone()),
+            (None, true) => None,
+            (None, false) => Some(
+                props
+                    .initial_language
+                    .clone()
+                    .unwrap_or_else(|| props.default_language.clone()),
+            ),
+        };
+        let result = match &known_language {
+            Some(language) => i18n.set_language(language),
+            None => Ok(()),
+        };
+
+        let store = new_store(i18n, &props);
+        let result = result.and_then(|_| match known_language {
+            Some(_) => Ok(()),
+            None => store.load_language().map(|_| ()),
+        });
+        if let Err(err) = result {
+            report(err);
+        }
+        if props.sync_tabs {
+            store.sync_tabs(props.sync_channel.as_deref());
+        }
+        store
+    });
+
+    let i18n = use_signal(|| store.i18n());
+
+    let subscription = use_hook(|| {
+        let onchange = props.onchange;
+        let subscribed = store.clone();
+        store.subscribe(move |event| {
+            let mut i18n = i18n;
+            match event {
+                StoreEvent::LanguageChanged(event) => {
+                    i18n.set(subscribed.i18n());
+                    onchange.call(event.language.clone());
+                }
+                StoreEvent::CatalogChanged => i18n.set(subscribed.i18n()),
+                StoreEvent::ChangeDeferred(_) | StoreEvent::ChangeCancelled(_) => {}
+                StoreEvent::Error(err) => report(err.clone()),
+            }
+        })
+    });
+
+    use_drop({
+        let store = store.clone();
+        move || {
+            store.unsubscribe(subscription);
+            store.stop_sync();
+        }
+    });
+
+    let set_language = use_callback({
+        let store = store.clone();
+        move |language: String| {
+            let _ = store.set_language(&language);
+        }
+    });
+
+    use_context_provider(|| I18nContext {
+        i18n,
+        set_language,
+        errors,
+    });
+
+    let hydrated = embedded.is_some();
+    use_effect(move || {
+        if hydrated {
+            let _ = store.redetect();
+        }
+    });
+
+    let marker = (props.initial_language.is_some() || hydrated)
+        .then(|| i18n.read().get_current_language().to_string());
+
+    rsx! {
+        if let Some(language) = marker {
+            template { "data-i18nrs-language": language }
+        }
+        {props.children}
+    }
+}
+
+/// Wraps a catalog in a store reading and persisting the language, and detecting it, as configured
+/// by the provider's properties.
+fn new_store(i18n: I18n, props: &I18nProviderProps) -> I18nStore {
+    let store = I18nStore::new(i18n);
+    let language_store = props.storage_type.resolve();
+    let store = if props.persist {
+        store.persist_to(language_store, props.storage_name.clone())
+    } else {
+        store.read_from(language_store, props.storage_name.clone())
+    };
+    store.detect_from(
+        props.detection.clone(),
+        props.query_param.clone(),
+        props.default_language.clone(),
+    )
+}
+
+/// Retrieves the i18n state and the language switcher provided by the nearest `I18nProvider`.
+///
+/// Reading the signal (e.g., `i18n.read().t("greeting")`) subscribes the component to language changes.
+///
+/// # Returns
+/// - A read-only signal of the `I18n` state.
+/// - A callback switching to the given language code.
+///
+/// # Panics
+/// - If called outside an `I18nProvider`, or inside one whose `fallback` is rendered.
+pub fn use_translation() -> (ReadOnlySignal<I18n>, Callback<String>) {
+    let context = use_context::<I18nContext>();
+    (context.i18n.into(), context.set_language)
+}
@@ -0,0 +1,719 @@
+/// Writing direction of a language's script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextDirection {
+    /// Left-to-right scripts such as Latin, Cyrillic or Devanagari.
+    #[default]
+    Ltr,
+    /// Right-to-left scripts such as Arabic or Hebrew.
+    Rtl,
+}
+
+impl TextDirection {
+    /// Returns the value expected by the HTML `dir` attribute (`"ltr"` or `"rtl"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextDirection::Ltr => "ltr",
+            TextDirection::Rtl => "rtl",
+        }
+    }
+}
+
+/// Static metadata describing a language.
+///
+/// Built-in values are derived from CLDR data and can be overridden per language through
+/// `I18nConfig::metadata`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LanguageMetadata {
+    /// The language name written in the language itself (e.g., `"français"`).
+    pub native_name: &'static str,
+    /// The language name in English (e.g., `"French"`).
+    pub english_name: &'static str,
+    /// The ISO 15924 code of the default script (e.g., `"Latn"`, `"Arab"`).
+    pub script: &'static str,
+    /// The writing direction of the default script.
+    pub direction: TextDirection,
+    /// An optional flag emoji for the language's most likely region.
+    pub flag: Option<&'static str>,
+}
+
+impl LanguageMetadata {
+    /// Looks up the built-in CLDR metadata for a BCP 47 language code.
+    ///
+    /// The full code is tried first (e.g., `"zh-Hant"`), then its primary language subtag (e.g., `"zh"`).
+    ///
+    /// # Arguments
+    /// - `code`: The language code to look up.
+    ///
+    /// # Returns
+    /// - `Some(LanguageMetadata)` if the language is known.
+    /// - `None` otherwise.
+    pub fn lookup(code: &str) -> Option<LanguageMetadata> {
+        let normalized = code.replace('_', "-");
+        let primary = normalized.split('-').next().unwrap_or_default();
+
+        find(&normalized).or_else(|| find(primary))
+    }
+}
+
+/// Metadata of a configured language, resolved for the current UI language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageInfo {
+    /// The language code as configured (e.g., `"fr"`).
+    pub code: String,
+    /// The language name written in the language itself (e.g., `"français"`).
+    pub native_name: String,
+    /// The language name in English (e.g., `"French"`).
+    pub english_name: String,
+    /// The language name in the current UI language (e.g., `"francés"` when browsing in Spanish).
+    pub localized_name: String,
+    /// The ISO 15924 code of the default script (e.g., `"Latn"`).
+    pub script: String,
+    /// The writing direction of the default script.
+    pub direction: TextDirection,
+    /// An optional flag emoji for the language's most likely region.
+    pub flag: Option<String>,
+}
+
+/// A built-in language entry: `(code, native name, English name, script, direction, flag)`.
+type LanguageEntry = (
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    TextDirection,
+    Option<&'static str>,
+);
+
+/// Built-in language table.
+///
+/// Names, scripts and likely regions follow the CLDR locale display names and likely subtags.
+const LANGUAGES: &[LanguageEntry] = &[
+    (
+        "af",
+        "Afrikaans",
+        "Afrikaans",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇿🇦"),
+    ),
+    (
+        "am",
+        "አማርኛ",
+        "Amharic",
+        "Ethi",
+        TextDirection::Ltr,
+        Some("🇪🇹"),
+    ),
+    (
+        "ar",
+        "العربية",
+        "Arabic",
+        "Arab",
+        TextDirection::Rtl,
+        Some("🇪🇬"),
+    ),
+    (
+        "az",
+        "azərbaycan",
+        "Azerbaijani",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇦🇿"),
+    ),
+    (
+        "be",
+        "беларуская",
+        "Belarusian",
+        "Cyrl",
+        TextDirection::Ltr,
+        Some("🇧🇾"),
+    ),
+    (
+        "bg",
+        "български",
+        "Bulgarian",
+        "Cyrl",
+        TextDirection::Ltr,
+        Some("🇧🇬"),
+    ),
+    (
+        "bn",
+        "বাংলা",
+        "Bangla",
+        "Beng",
+        TextDirection::Ltr,
+        Some("🇧🇩"),
+    ),
+    (
+        "bs",
+        "bosanski",
+        "Bosnian",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇧🇦"),
+    ),
+    (
+        "ca",
+        "català",
+        "Catalan",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇪🇸"),
+    ),
+    (
+        "cs",
+        "čeština",
+        "Czech",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇨🇿"),
+    ),
+    (
+        "cy",
+        "Cymraeg",
+        "Welsh",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇬🇧"),
+    ),
+    (
+        "da",
+        "dansk",
+        "Danish",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇩🇰"),
+    ),
+    (
+        "de",
+        "Deutsch",
+        "German",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇩🇪"),
+    ),
+    (
+        "el",
+        "Ελληνικά",
+        "Greek",
+        "Grek",
+        TextDirection::Ltr,
+        Some("🇬🇷"),
+    ),
+    (
+        "en",
+        "English",
+        "English",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇺🇸"),
+    ),
+    (
+        "es",
+        "español",
+        "Spanish",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇪🇸"),
+    ),
+    (
+        "et",
+        "eesti",
+        "Estonian",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇪🇪"),
+    ),
+    (
+        "eu",
+        "euskara",
+        "Basque",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇪🇸"),
+    ),
+    (
+        "fa",
+        "فارسی",
+        "Persian",
+        "Arab",
+        TextDirection::Rtl,
+        Some("🇮🇷"),
+    ),
+    (
+        "fi",
+        "suomi",
+        "Finnish",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇫🇮"),
+    ),
+    (
+        "fil",
+        "Filipino",
+        "Filipino",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇵🇭"),
+    ),
+    (
+        "fr",
+        "français",
+        "French",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇫🇷"),
+    ),
+    (
+        "ga",
+        "Gaeilge",
+        "Irish",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇮🇪"),
+    ),
+    (
+        "gl",
+        "galego",
+        "Galician",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇪🇸"),
+    ),
+    (
+        "gu",
+        "ગુજરાતી",
+        "Gujarati",
+        "Gujr",
+        TextDirection::Ltr,
+        Some("🇮🇳"),
+    ),
+    (
+        "he",
+        "עברית",
+        "Hebrew",
+        "Hebr",
+        TextDirection::Rtl,
+        Some("🇮🇱"),
+    ),
+    (
+        "hi",
+        "हिन्दी",
+        "Hindi",
+        "Deva",
+        TextDirection::Ltr,
+        Some("🇮🇳"),
+    ),
+    (
+        "hr",
+        "hrvatski",
+        "Croatian",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇭🇷"),
+    ),
+    (
+        "hu",
+        "magyar",
+        "Hungarian",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇭🇺"),
+    ),
+    (
+        "hy",
+        "հայերեն",
+        "Armenian",
+        "Armn",
+        TextDirection::Ltr,
+        Some("🇦🇲"),
+    ),
+    (
+        "id",
+        "Indonesia",
+        "Indonesian",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇮🇩"),
+    ),
+    (
+        "is",
+        "íslenska",
+        "Icelandic",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇮🇸"),
+    ),
+    (
+        "it",
+        "italiano",
+        "Italian",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇮🇹"),
+    ),
+    (
+        "ja",
+        "日本語",
+        "Japanese",
+        "Jpan",
+        TextDirection::Ltr,
+        Some("🇯🇵"),
+    ),
+    (
+        "ka",
+        "ქართული",
+        "Georgian",
+        "Geor",
+        TextDirection::Ltr,
+        Some("🇬🇪"),
+    ),
+    (
+        "kk",
+        "қазақ тілі",
+        "Kazakh",
+        "Cyrl",
+        TextDirection::Ltr,
+        Some("🇰🇿"),
+    ),
+    ("km", "ខ្មែរ", "Khmer", "Khmr", TextDirection::Ltr, Some("🇰🇭")),
+    (
+        "kn",
+        "ಕನ್ನಡ",
+        "Kannada",
+        "Knda",
+        TextDirection::Ltr,
+        Some("🇮🇳"),
+    ),
+    (
+        "ko",
+        "한국어",
+        "Korean",
+        "Kore",
+        TextDirection::Ltr,
+        Some("🇰🇷"),
+    ),
+    ("lo", "ລາວ", "Lao", "Laoo", TextDirection::Ltr, Some("🇱🇦")),
+    (
+        "lt",
+        "lietuvių",
+        "Lithuanian",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇱🇹"),
+    ),
+    (
+        "lv",
+        "latviešu",
+        "Latvian",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇱🇻"),
+    ),
+    (
+        "mk",
+        "македонски",
+        "Macedonian",
+        "Cyrl",
+        TextDirection::Ltr,
+        Some("🇲🇰"),
+    ),
+    (
+        "ml",
+        "മലയാളം",
+        "Malayalam",
+        "Mlym",
+        TextDirection::Ltr,
+        Some("🇮🇳"),
+    ),
+    (
+        "mn",
+        "монгол",
+        "Mongolian",
+        "Cyrl",
+        TextDirection::Ltr,
+        Some("🇲🇳"),
+    ),
+    (
+        "mr",
+        "मराठी",
+        "Marathi",
+        "Deva",
+        TextDirection::Ltr,
+        Some("🇮🇳"),
+    ),
+    (
+        "ms",
+        "Melayu",
+        "Malay",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇲🇾"),
+    ),
+    (
+        "my",
+        "မြန်မာ",
+        "Burmese",
+        "Mymr",
+        TextDirection::Ltr,
+        Some("🇲🇲"),
+    ),
+    (
+        "nb",
+        "norsk bokmål",
+        "Norwegian Bokmål",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇳🇴"),
+    ),
+    (
+        "ne",
+        "नेपाली",
+        "Nepali",
+        "Deva",
+        TextDirection::Ltr,
+        Some("🇳🇵"),
+    ),
+    (
+        "nl",
+        "Nederlands",
+        "Dutch",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇳🇱"),
+    ),
+    (
+        "nn",
+        "norsk nynorsk",
+        "Norwegian Nynorsk",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇳🇴"),
+    ),
+    (
+        "no",
+        "norsk",
+        "Norwegian",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇳🇴"),
+    ),
+    (
+        "pa",
+        "ਪੰਜਾਬੀ",
+        "Punjabi",
+        "Guru",
+        TextDirection::Ltr,
+        Some("🇮🇳"),
+    ),
+    (
+        "pl",
+        "polski",
+        "Polish",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇵🇱"),
+    ),
+    (
+        "ps",
+        "پښتو",
+        "Pashto",
+        "Arab",
+        TextDirection::Rtl,
+        Some("🇦🇫"),
+    ),
+    (
+        "pt",
+        "português",
+        "Portuguese",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇧🇷"),
+    ),
+    (
+        "ro",
+        "română",
+        "Romanian",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇷🇴"),
+    ),
+    (
+        "ru",
+        "русский",
+        "Russian",
+        "Cyrl",
+        TextDirection::Ltr,
+        Some("🇷🇺"),
+    ),
+    (
+        "si",
+        "සිංහල",
+        "Sinhala",
+        "Sinh",
+        TextDirection::Ltr,
+        Some("🇱🇰"),
+    ),
+    (
+        "sk",
+        "slovenčina",
+        "Slovak",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇸🇰"),
+    ),
+    (
+        "sl",
+        "slovenščina",
+        "Slovenian",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇸🇮"),
+    ),
+    (
+        "sq",
+        "shqip",
+        "Albanian",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇦🇱"),
+    ),
+    (
+        "sr",
+        "српски",
+        "Serbian",
+        "Cyrl",
+        TextDirection::Ltr,
+        Some("🇷🇸"),
+    ),
+    (
+        "sv",
+        "svenska",
+        "Swedish",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇸🇪"),
+    ),
+    (
+        "sw",
+        "Kiswahili",
+        "Swahili",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇹🇿"),
+    ),
+    (
+        "ta",
+        "தமிழ்",
+        "Tamil",
+        "Taml",
+        TextDirection::Ltr,
+        Some("🇮🇳"),
+    ),
+    (
+        "te",
+        "తెలుగు",
+        "Telugu",
+        "Telu",
+        TextDirection::Ltr,
+        Some("🇮🇳"),
+    ),
+    ("th", "ไทย", "Thai", "Thai", TextDirection::Ltr, Some("🇹🇭")),
+    (
+        "tr",
+        "Türkçe",
+        "Turkish",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇹🇷"),
+    ),
+    (
+        "uk",
+        "українська",
+        "Ukrainian",
+        "Cyrl",
+        TextDirection::Ltr,
+        Some("🇺🇦"),
+    ),
+    ("ur", "اردو", "Urdu", "Arab", TextDirection::Rtl, Some("🇵🇰")),
+    (
+        "uz",
+        "o‘zbek",
+        "Uzbek",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇺🇿"),
+    ),
+    (
+        "vi",
+        "Tiếng Việt",
+        "Vietnamese",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇻🇳"),
+    ),
+    ("yi", "ייִדיש", "Yiddish", "Hebr", TextDirection::Rtl, None),
+    (
+        "zh",
+        "中文",
+        "Chinese",
+        "Hans",
+        TextDirection::Ltr,
+        Some("🇨🇳"),
+    ),
+    (
+        "zh-Hans",
+        "简体中文",
+        "Simplified Chinese",
+        "Hans",
+        TextDirection::Ltr,
+        Some("🇨🇳"),
+    ),
+    (
+        "zh-Hant",
+        "繁體中文",
+        "Traditional Chinese",
+        "Hant",
+        TextDirection::Ltr,
+        Some("🇹🇼"),
+    ),
+    (
+        "zu",
+        "isiZulu",
+        "Zulu",
+        "Latn",
+        TextDirection::Ltr,
+        Some("🇿🇦"),
+    ),
+];
+
+/// Finds an entry of the built-in table, ignoring ASCII case.
+fn find(code: &str) -> Option<LanguageMetadata> {
+    LANGUAGES
+        .iter()
+        .find(|(candidate, ..)| candidate.eq_ignore_ascii_case(code))
+        .map(
+            |&(_, native_name, english_name, script, direction, flag)| LanguageMetadata {
+                native_name,
+                english_name,
+                script,
+                direction,
+                flag,
+            },
+        )
+}
+
+/// Extracts the two-letter region subtag of a BCP 47 code, if any (e.g., `"BR"` in `"pt-BR"`).
+pub(crate) fn region_subtag(code: &str) -> Option<&str> {
+    code.split(['-', '_'])
+        .skip(1)
+        .find(|subtag| subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+}
+
+/// Builds the flag emoji of a two-letter region code out of regional indicator symbols.
+pub(crate) fn region_flag(region: &str) -> Option<String> {
+    region
+        .chars()
+        .map(|c| char::from_u32(0x1F1E6 + (c.to_ascii_uppercase() as u32).checked_sub('A' as u32)?))
+        .collect()
+}
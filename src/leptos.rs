@@ -0,0 +1,320 @@
+#![doc = include_str!("../LEPTOS.md")]
+
+use crate::config::{I18n, I18nConfig, StorageType};
+use crate::detect::{embedded_language, LanguageSource};
+use crate::language::LanguageMetadata;
+use crate::store::{I18nStore, StoreEvent};
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+/// The i18n context provided by `I18nProvider`.
+///
+/// It is `Copy`, so it can be moved into as many closures as needed. Translations read through `t`
+/// are reactive: only the text nodes depending on them update when the language changes.
+#[derive(Debug, Clone, Copy)]
+pub struct I18nContext {
+    i18n: RwSignal<I18n>,
+    set_language: Callback<String>,
+    errors: RwSignal<Vec<String>>,
+}
+
+impl I18nContext {
+    /// Translates a given key, tracking the current language.
+    ///
+    /// # Arguments
+    /// - `key`: The translation key to retrieve (e.g., `"menu.file.open"`).
+    ///
+    /// # Returns
+    /// - A signal of the translated string, updated whenever the language changes.
+    pub fn t(&self, key: &str) -> Signal<String> {
+        let i18n = self.i18n;
+        let key = key.to_string();
+        Signal::derive(move || i18n.with(|i18n| i18n.t(&key)))
+    }
+
+    /// Retrieves the current language.
+    ///
+    /// # Returns
+    /// - A signal of the current language code.
+    pub fn language(&self) -> Signal<String> {
+        let i18n = self.i18n;
+        Signal::derive(move || i18n.with(|i18n| i18n.get_current_language().to_string()))
+    }
+
+    /// Switches to the given language through the provider, persisting it and calling `onchange`.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to switch to (e.g., `"fr"`).
+    pub fn set_language(&self, language: impl Into<String>) {
+        self.set_language.run(language.into());
+    }
+
+    /// Retrieves the underlying `I18n` state, e.g., to call `language_info` or `localize_path`.
+    ///
+    /// # Returns
+    /// - A read-only signal of the `I18n` state.
+    pub fn i18n(&self) -> ReadSignal<I18n> {
+        self.i18n.read_only()
+    }
+
+    /// Retrieves the errors reported so far, including the languages that failed to load.
+    ///
+    /// # Returns
+    /// - A read-only signal of the error messages, in order.
+    pub fn errors(&self) -> ReadSignal<Vec<String>> {
+        self.errors.read_only()
+    }
+}
+
+/// I18nProvider Component
+///
+/// A Leptos component that provides internationalization (i18n) context to its children.
+/// The properties mirror the Yew `I18nProvider`.
+///
+/// # Examples
+/// ```rust
+/// use leptos::prelude::*;
+/// use i18nrs::leptos::{use_translation, I18nProvider};
+/// use std::collections::HashMap;
+///
+/// #[component]
+/// fn App(language: String) -> impl IntoView {
+///     let translations = HashMap::from([
+///         ("en", r#"{"greeting": "Hello"}"#),
+///         ("fr", r#"{"greeting": "Bonjour"}"#),
+///     ]);
+///
+///     view! {
+///         <I18nProvider languages=vec!["en", "fr"] translations=translations initial_language=language>
+///             <Greeting />
+///         </I18nProvider>
+///     }
+/// }
+///
+/// #[component]
+/// fn Greeting() -> impl IntoView {
+///     let i18n = use_translation();
+///     view! { <h1>{i18n.t("greeting")}</h1> }
+/// }
+///
+/// let rendered = Owner::new().with(|| view! { <App language="fr".to_string() /> }.to_html());
+///
+/// assert!(rendered.contains("Bonjour"));
+/// assert!(rendered.contains(r#"data-i18nrs-language="fr""#));
+/// ```
+///
+/// # Behavior
+/// - In the browser, picks the initial language by consulting the `detection` sources in order, and
+///   persists it in `storage_type` unless `persist` is `false`.
+/// - Outside the browser (i.e., not on `wasm32`), no browser API is used: the language is `initial_language`,
+///   or `default_language` if unset.
+/// - When `initial_language` is set, the provider embeds it in the markup. While hydrating, the client
+///   reads it back so its first render matches the server, then runs `detection` and switches if needed.
+/// - Calls `onchange` with the new language code after each switch, and `onerror` on initialization
+///   or persistence errors.
+/// - Never panics on bad translations: each language that fails to load is reported through `onerror`
+///   and left out, and `fallback` is rendered if no language loads at all.
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use i18nrs::leptos::I18nProvider;
+/// use std::collections::HashMap;
+///
+/// let rendered = Owner::new().with(|| {
+///     view! {
+///         <I18nProvider
+///             languages=vec!["en"]
+///             translations=HashMap::from([("en", r#"{"greeting": "#)])
+///             onerror=|err: String| log::error!("{}", err)
+///             fallback=|| view! { <p>"Translations are unavailable"</p> }
+///         >
+///             <h1>"Unreachable"</h1>
+///         </I18nProvider>
+///     }
+///     .to_html()
+/// });
+///
+/// assert!(rendered.contains("Translations are unavailable"));
+/// assert!(!rendered.contains("Unreachable"));
+/// ```
+#[component]
+pub fn I18nProvider(
+    /// List of supported languages. Defaults to `["en", "fr"]`.
+    #[prop(default = vec!["en", "fr"])]
+    languages: Vec<&'static str>,
+    /// The mapping of language codes to raw translation content. Defaults to an empty `HashMap`.
+    #[prop(optional)]
+    translations: HashMap<&'static str, &'static str>,
+    /// Per-language overrides of the built-in CLDR metadata. Defaults to an empty `HashMap`.
+    #[prop(optional)]
+    metadata: HashMap<&'static str, LanguageMetadata>,
+    /// The type of browser storage to use. Defaults to `StorageType::LocalStorage`.
+    #[prop(optional)]
+    storage_type: StorageType,
+    /// The key for storing the selected language. Defaults to `"i18nrs"`.
+    #[prop(into, default = "i18nrs".to_string())]
+    storage_name: String,
+    /// Whether to persist the selected language in `storage_type`. Defaults to `true`.
+    #[prop(default = true)]
+    persist: bool,
+    /// Default language if no language is found in storage. Defaults to `"en"`.
+    #[prop(into, default = "en".to_string())]
+    default_language: String,
+    /// Sources consulted, in order, to pick the initial language. Defaults to `[Storage, Default]`.
+    #[prop(default = vec![LanguageSource::Storage, LanguageSource::Default])]
+    detection: Vec<LanguageSource>,
+    /// The language resolved by the server for server-side rendering. Defaults to `None`.
+    #[prop(optional, into)]
+    initial_language: Option<String>,
+    /// The URL query parameter read by `LanguageSource::Url`. Defaults to `"lang"`.
+    #[prop(into, default = "lang".to_string())]
+    query_param: String,
+    /// Whether to apply language changes made in other tabs, through `storage` events. Defaults to `true`.
+    #[prop(default = true)]
+    sync_tabs: bool,
+    /// The `BroadcastChannel` shared with other tabs, for storages without `storage` events. Defaults to `None`.
+    #[prop(optional, into)]
+    sync_channel: Option<String>,
+    /// Callback when the language changes, receiving the new language code.
+    #[prop(optional, into)]
+    onchange: Option<Callback<String>>,
+    /// Callback for handling errors, receiving the error message.
+    #[prop(optional, into)]
+    onerror: Option<Callback<String>>,
+    /// Rendered instead of the children if no language could be loaded. Defaults to nothing.
+    #[prop(optional, into)]
+    fallback: ViewFn,
+    /// The children wrapped with the i18n context.
+    children: Children,
+) -> impl IntoView {
+    let errors = RwSignal::new(Vec::new());
+    let report = move |err: String| {
+        errors.update(|errors| errors.push(err.clone()));
+        if let Some(onerror) = onerror {
+            onerror.run(err);
+        }
+    };
+
+    let (mut instance, load_errors) = match I18n::new_lenient(
+        I18nConfig {
+            languages,
+            translations: translations.clone(),
+            metadata,
+        },
+        translations,
+    ) {
+        Ok(loaded) => loaded,
+        Err(load_errors) => {
+            load_errors.into_iter().for_each(report);
+            return fallback.run().into_any();
+        }
+    };
+    load_errors.into_iter().for_each(report);
+
+    let embedded = if cfg!(target_arch = "wasm32") {
+        embedded_language()
+    } else {
+        None
+    };
+
+    let known_language = match (&embedded, cfg!(target_arch = "wasm32")) {
+        (Some(language), _) => Some(language.clone()),
+        (None, true) => None,
+        (None, false) => Some(
+            initial_language
+                .clone()
+                .unwrap_or_else(|| default_language.clone()),
+        ),
+    };
+    let result = match &known_language {
+        Some(language) => instance.set_language(language),
+        None => Ok(()),
+    };
+
+    let language_store = storage_type.resolve();
+    let store = I18nStore::new(instance);
+    let store = if persist {
+        store.persist_to(language_store, storage_name)
+    } else {
+        store.read_from(language_store, storage_name)
+    }
+    .detect_from(detection, query_param, default_language);
+
+    let result = result.and_then(|_| match known_language {
+        Some(_) => Ok(()),
+        None => store.load_language().map(|_| ()),
+    });
+    if let Err(err) = result {
+        report(err);
+    }
+    if sync_tabs {
+        store.sync_tabs(sync_channel.as_deref());
+    }
+
+    let i18n = RwSignal::new(store.i18n());
+    let subscription = store.subscribe({
+        let subscribed = store.clone();
+        move |event| match event {
+            StoreEvent::LanguageChanged(event) => {
+                i18n.set(subscribed.i18n());
+                if let Some(onchange) = onchange {
+                    onchange.run(event.language.clone());
+                }
+            }
+            StoreEvent::CatalogChanged => i18n.set(subscribed.i18n()),
+            StoreEvent::ChangeDeferred(_) | StoreEvent::ChangeCancelled(_) => {}
+            StoreEvent::Error(err) => report(err.clone()),
+        }
+    });
+
+    // Cloned out before each use, so the store is free to call back into the subscriber.
+    let store = StoredValue::new_local(store);
+    on_cleanup(move || {
+        if let Some(store) = store.try_get_value() {
+            store.unsubscribe(subscription);
+            store.stop_sync();
+        }
+    });
+
+    let set_language = Callback::new(move |language: String| {
+        let _ = store.get_value().set_language(&language);
+    });
+
+    provide_context(I18nContext {
+        i18n,
+        set_language,
+        errors,
+    });
+
+    let hydrated = embedded.is_some();
+    Effect::new(move |_| {
+        if hydrated {
+            let _ = store.get_value().redetect();
+        }
+    });
+
+    let marker = (initial_language.is_some() || hydrated).then(|| {
+        view! {
+            <template data-i18nrs-language=move || {
+                i18n.with(|i18n| i18n.get_current_language().to_string())
+            } />
+        }
+    });
+
+    view! {
+        {marker}
+        {children()}
+    }
+    .into_any()
+}
+
+/// Retrieves the i18n context provided by the nearest `I18nProvider`.
+///
+/// # Returns
+/// - The `I18nContext`, exposing the reactive `t`, the current language and the language switcher.
+///
+/// # Panics
+/// - If called outside an `I18nProvider`.
+pub fn use_translation() -> I18nContext {
+    expect_context::<I18nContext>()
+}
@@ -8,6 +8,36 @@
 #[cfg(feature = "yew")]
 pub mod yew;
 
+#[cfg(feature = "dioxus")]
+pub mod dioxus;
+
+#[cfg(feature = "leptos")]
+pub mod leptos;
+
+#[cfg(feature = "axum")]
+pub mod axum;
+
+#[cfg(feature = "cli")]
+pub mod lint;
+
 pub mod config;
+pub mod cookie;
+pub mod detect;
+pub mod language;
+pub mod message;
+pub mod missing;
+pub mod negotiate;
+pub mod routing;
+pub mod storage;
+pub mod store;
+pub mod sync;
 
 pub use config::{I18n, I18nConfig, StorageType};
+pub use cookie::{CookieOptions, SameSite};
+pub use detect::LanguageSource;
+pub use language::{LanguageInfo, LanguageMetadata, TextDirection};
+pub use negotiate::{negotiate_accept_language, parse_accept_language, Negotiation};
+pub use storage::{LanguageStore, MemoryStore};
+pub use store::{
+    ChangeDecision, ChangeSource, I18nStore, LanguageChangeEvent, StoreEvent, SubscriptionId,
+};
@@ -0,0 +1,640 @@
+//! Linting of translation catalogs, as run by the `i18nrs lint` command.
+//!
+//! Catalogs are compared with a reference language to find missing and extra keys, type mismatches
+//! (e.g., a string where the reference has an object) and empty values. Invalid JSON is reported with
+//! its line and column.
+
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Enum representing the severity of an `Issue`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// A problem visible to users, such as a missing key.
+    Error,
+    /// A likely mistake that does not break translations, such as an unused key.
+    Warning,
+}
+
+impl Severity {
+    /// Returns the name of the severity (`"error"` or `"warning"`), as used by SARIF.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// Enum representing the kinds of problems found in catalogs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IssueKind {
+    /// The file is not valid JSON.
+    InvalidJson,
+    /// A file of the reference language has no counterpart in another language.
+    MissingFile,
+    /// A key of the reference language is missing.
+    MissingKey,
+    /// A key is not in the reference language.
+    ExtraKey,
+    /// A value has a different JSON type than in the reference language (e.g., a string instead of an object).
+    TypeMismatch,
+    /// A translation is an empty string.
+    EmptyValue,
+}
+
+impl IssueKind {
+    /// All issue kinds, in reporting order.
+    pub const ALL: [IssueKind; 6] = [
+        IssueKind::InvalidJson,
+        IssueKind::MissingFile,
+        IssueKind::MissingKey,
+        IssueKind::ExtraKey,
+        IssueKind::TypeMismatch,
+        IssueKind::EmptyValue,
+    ];
+
+    /// Returns the rule identifier of the kind (e.g., `"missing-key"`).
+    pub fn id(&self) -> &'static str {
+        match self {
+            IssueKind::InvalidJson => "invalid-json",
+            IssueKind::MissingFile => "missing-file",
+            IssueKind::MissingKey => "missing-key",
+            IssueKind::ExtraKey => "extra-key",
+            IssueKind::TypeMismatch => "type-mismatch",
+            IssueKind::EmptyValue => "empty-value",
+        }
+    }
+
+    /// Returns a one-line description of the kind.
+    pub fn description(&self) -> &'static str {
+        match self {
+            IssueKind::InvalidJson => "The catalog is not valid JSON.",
+            IssueKind::MissingFile => "The catalog exists in the reference language only.",
+            IssueKind::MissingKey => "A key of the reference language is not translated.",
+            IssueKind::ExtraKey => "A key does not exist in the reference language.",
+            IssueKind::TypeMismatch => {
+                "A value has a different type than in the reference language."
+            }
+            IssueKind::EmptyValue => "A translation is empty.",
+        }
+    }
+
+    /// Returns the severity of the kind.
+    pub fn severity(&self) -> Severity {
+        match self {
+            IssueKind::ExtraKey | IssueKind::EmptyValue => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+/// A problem found in a catalog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    /// The kind of problem.
+    pub kind: IssueKind,
+    /// The catalog file.
+    pub file: PathBuf,
+    /// The language of the catalog.
+    pub language: String,
+    /// The dot-separated key concerned, if any (e.g., `"menu.file.open"`).
+    pub key: Option<String>,
+    /// A human-readable description of the problem.
+    pub message: String,
+    /// The 1-based line and column of the problem, when known.
+    pub position: Option<(usize, usize)>,
+}
+
+impl Issue {
+    /// Returns the severity of the issue.
+    pub fn severity(&self) -> Severity {
+        self.kind.severity()
+    }
+}
+
+/// A translation catalog to lint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Catalog {
+    /// The language code (e.g., `"fr"`).
+    pub language: String,
+    /// The namespace, used to pair the files of different languages (e.g., `"base"` for `fr/base.json`,
+    /// or `"admin/users"` for `fr/admin/users.json`).
+    pub namespace: String,
+    /// The path of the file, used in reports.
+    pub file: PathBuf,
+    /// The raw JSON content.
+    pub source: String,
+}
+
+/// Reads the catalogs of a directory.
+///
+/// Two layouts are supported: one directory per language holding one file per namespace
+/// (`en/base.json`, `fr/base.json`), or one file per language (`en.json`, `fr.json`). Namespaces may be
+/// nested in subdirectories: `fr/admin/users.json` holds the `admin/users` namespace.
+///
+/// # Arguments
+/// - `dir`: The directory to read (e.g., `"i18n"`).
+///
+/// # Returns
+/// - `Ok(Vec<Catalog>)` with the catalogs, sorted by namespace and language.
+/// - `Err(String)` if the directory or one of its files cannot be read.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::lint::{lint_catalogs, read_catalogs, IssueKind};
+/// use std::fs;
+///
+/// let dir = std::env::temp_dir().join(format!("i18nrs-lint-{}", std::process::id()));
+/// fs::create_dir_all(dir.join("en/admin")).unwrap();
+/// fs::create_dir_all(dir.join("fr/admin")).unwrap();
+/// fs::write(dir.join("en/base.json"), r#"{"title": "Editor"}"#).unwrap();
+/// fs::write(dir.join("fr/base.json"), r#"{"title": "Éditeur"}"#).unwrap();
+/// fs::write(dir.join("en/admin/users.json"), r#"{"ban": "Ban", "kick": "Kick"}"#).unwrap();
+/// fs::write(dir.join("fr/admin/users.json"), r#"{"ban": "Bannir"}"#).unwrap();
+///
+/// let catalogs = read_catalogs(&dir).unwrap();
+/// let issues = lint_catalogs(&catalogs, "en").unwrap();
+/// fs::remove_dir_all(&dir).unwrap();
+///
+/// let namespaces: Vec<_> = catalogs
+///     .iter()
+///     .map(|catalog| (catalog.namespace.as_str(), catalog.language.as_str()))
+///     .collect();
+/// assert_eq!(
+///     namespaces,
+///     [("admin/users", "en"), ("admin/users", "fr"), ("base", "en"), ("base", "fr")]
+/// );
+/// assert_eq!(issues.len(), 1);
+/// assert_eq!(issues[0].kind, IssueKind::MissingKey);
+/// assert_eq!(issues[0].key.as_deref(), Some("kick"));
+/// ```
+pub fn read_catalogs(dir: &Path) -> Result<Vec<Catalog>, String> {
+    let mut catalogs = Vec::new();
+    for path in entries(dir)? {
+        if path.is_dir() {
+            let language = file_name(&path);
+            read_namespaces(&path, &language, "", &mut catalogs)?;
+        } else if is_json(&path) {
+            let language = file_stem(&path);
+            catalogs.push(read_catalog(path, language, String::new())?);
+        }
+    }
+
+    catalogs.sort_by(|a, b| (&a.namespace, &a.language).cmp(&(&b.namespace, &b.language)));
+    Ok(catalogs)
+}
+
+/// Reads the catalogs of a language directory, recursing into nested namespaces.
+fn read_namespaces(
+    dir: &Path,
+    language: &str,
+    prefix: &str,
+    catalogs: &mut Vec<Catalog>,
+) -> Result<(), String> {
+    for path in entries(dir)? {
+        let name = if path.is_dir() {
+            file_name(&path)
+        } else {
+            file_stem(&path)
+        };
+        let namespace = if prefix.is_empty() {
+            name
+        } else {
+            format!("{}/{}", prefix, name)
+        };
+
+        if path.is_dir() {
+            read_namespaces(&path, language, &namespace, catalogs)?;
+        } else if is_json(&path) {
+            catalogs.push(read_catalog(path, language.to_string(), namespace)?);
+        }
+    }
+    Ok(())
+}
+
+/// Reads a catalog file.
+fn read_catalog(file: PathBuf, language: String, namespace: String) -> Result<Catalog, String> {
+    let source = fs::read_to_string(&file)
+        .map_err(|err| format!("Failed to read {}: {}", file.display(), err))?;
+    Ok(Catalog {
+        language,
+        namespace,
+        file,
+        source,
+    })
+}
+
+/// Lists the paths of the entries of a directory.
+fn entries(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    fs::read_dir(dir)
+        .and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect()
+        })
+        .map_err(|err| format!("Failed to read {}: {}", dir.display(), err))
+}
+
+/// Returns whether a path has the `json` extension.
+fn is_json(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "json")
+}
+
+/// Returns the file name of a path.
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Returns the file name of a path without its extension.
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Lints catalogs against a reference language.
+///
+/// # Arguments
+/// - `catalogs`: The catalogs to lint, e.g., from `read_catalogs`.
+/// - `reference`: The language whose keys every other language must translate (e.g., `"en"`).
+///
+/// # Returns
+/// - The issues found, grouped by namespace and language.
+/// - `Err(String)` if no catalog has the reference language.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::lint::{lint_catalogs, Catalog, IssueKind};
+///
+/// let catalog = |language: &str, source: &str| Catalog {
+///     language: language.to_string(),
+///     namespace: "base".to_string(),
+///     file: format!("i18n/{}/base.json", language).into(),
+///     source: source.to_string(),
+/// };
+/// let catalogs = [
+///     catalog("en", r#"{"menu": {"open": "Open", "save": "Save"}, "title": "Editor"}"#),
+///     catalog("fr", r#"{"menu": {"open": "Ouvrir"}, "title": {"short": "Éditeur"}, "extra": ""}"#),
+///     catalog("es", "{\n  \"menu\": {\n"),
+/// ];
+///
+/// let issues = lint_catalogs(&catalogs, "en").unwrap();
+/// let kinds: Vec<_> = issues.iter().map(|issue| (issue.language.as_str(), issue.kind)).collect();
+/// assert_eq!(
+///     kinds,
+///     [
+///         ("es", IssueKind::InvalidJson),
+///         ("fr", IssueKind::MissingKey),
+///         ("fr", IssueKind::TypeMismatch),
+///         ("fr", IssueKind::ExtraKey),
+///         ("fr", IssueKind::EmptyValue),
+///     ]
+/// );
+/// assert_eq!(issues[0].position, Some((3, 1)));
+/// assert_eq!(issues[0].message, "Invalid JSON: EOF while parsing an object");
+/// assert_eq!(issues[1].key.as_deref(), Some("menu.save"));
+/// ```
+pub fn lint_catalogs(catalogs: &[Catalog], reference: &str) -> Result<Vec<Issue>, String> {
+    if !catalogs.iter().any(|catalog| catalog.language == reference) {
+        return Err(format!(
+            "No catalog found for the reference language '{}'",
+            reference
+        ));
+    }
+
+    let mut issues = Vec::new();
+    let mut parsed = Vec::new();
+    for catalog in catalogs {
+        match serde_json::from_str::<Value>(&catalog.source) {
+            Ok(value) => parsed.push((catalog, value)),
+            Err(err) => {
+                // The position is reported on its own, and serde_json may place it in column 0.
+                let message = err.to_string();
+                let message = message
+                    .rsplit_once(" at line ")
+                    .map_or(message.as_str(), |(message, _)| message);
+                issues.push(Issue {
+                    kind: IssueKind::InvalidJson,
+                    file: catalog.file.clone(),
+                    language: catalog.language.clone(),
+                    key: None,
+                    message: format!("Invalid JSON: {}", message),
+                    position: Some((err.line().max(1), err.column().max(1))),
+                })
+            }
+        }
+    }
+
+    let mut namespaces: Vec<&str> = catalogs
+        .iter()
+        .map(|catalog| catalog.namespace.as_str())
+        .collect();
+    namespaces.sort();
+    namespaces.dedup();
+    let mut languages: Vec<&str> = catalogs
+        .iter()
+        .map(|catalog| catalog.language.as_str())
+        .filter(|language| *language != reference)
+        .collect();
+    languages.sort();
+    languages.dedup();
+
+    for namespace in namespaces {
+        let reference_catalog = catalogs
+            .iter()
+            .find(|catalog| catalog.namespace == namespace && catalog.language == reference);
+        let reference_value = parsed
+            .iter()
+            .find(|(catalog, _)| catalog.namespace == namespace && catalog.language == reference)
+            .map(|(_, value)| value);
+
+        if let Some(reference_catalog) = reference_catalog {
+            for language in &languages {
+                let exists = catalogs
+                    .iter()
+                    .any(|catalog| catalog.namespace == namespace && catalog.language == *language);
+                if !exists {
+                    issues.push(Issue {
+                        kind: IssueKind::MissingFile,
+                        file: reference_catalog.file.clone(),
+                        language: language.to_string(),
+                        key: None,
+                        message: format!("No '{}' catalog matches this file", language),
+                        position: None,
+                    });
+                }
+            }
+        }
+
+        for (catalog, value) in parsed
+            .iter()
+            .filter(|(catalog, _)| catalog.namespace == namespace)
+        {
+            let mut lint = CatalogLint {
+                catalog,
+                reference,
+                issues: &mut issues,
+            };
+            match reference_value.filter(|_| catalog.language != reference) {
+                Some(reference_value) => lint.compare(reference_value, value, ""),
+                None => lint.empty_values(value, ""),
+            }
+        }
+    }
+
+    Ok(issues)
+}
+
+/// Collects the issues of one catalog.
+struct CatalogLint<'a> {
+    catalog: &'a Catalog,
+    reference: &'a str,
+    issues: &'a mut Vec<Issue>,
+}
+
+impl CatalogLint<'_> {
+    /// Compares a value with its reference counterpart, recursively.
+    fn compare(&mut self, reference: &Value, value: &Value, key: &str) {
+        match (reference, value) {
+            (Value::Object(reference), Value::Object(object)) => {
+                for (name, reference_value) in reference {
+                    let key = join(key, name);
+                    match object.get(name) {
+                        Some(value) => self.compare(reference_value, value, &key),
+                        None => self.missing(reference_value, &key),
+                    }
+                }
+                for (name, value) in object {
+                    if !reference.contains_key(name) {
+                        self.extra(value, &join(key, name));
+                    }
+                }
+            }
+            _ if type_name(reference) != type_name(value) => self.push(
+                IssueKind::TypeMismatch,
+                key,
+                format!(
+                    "`{}` is {} {} but {} {} in '{}'",
+                    key,
+                    article(type_name(value)),
+                    type_name(value),
+                    article(type_name(reference)),
+                    type_name(reference),
+                    self.reference
+                ),
+            ),
+            _ => self.empty_values(value, key),
+        }
+    }
+
+    /// Reports every leaf of a reference value as missing.
+    fn missing(&mut self, reference: &Value, key: &str) {
+        match reference {
+            Value::Object(object) if !object.is_empty() => {
+                for (name, value) in object {
+                    self.missing(value, &join(key, name));
+                }
+            }
+            _ => self.push(
+                IssueKind::MissingKey,
+                key,
+                format!("`{}` is missing (present in '{}')", key, self.reference),
+            ),
+        }
+    }
+
+    /// Reports every leaf of a value as extra.
+    fn extra(&mut self, value: &Value, key: &str) {
+        match value {
+            Value::Object(object) if !object.is_empty() => {
+                for (name, value) in object {
+                    self.extra(value, &join(key, name));
+                }
+            }
+            _ => {
+                self.push(
+                    IssueKind::ExtraKey,
+                    key,
+                    format!("`{}` does not exist in '{}'", key, self.reference),
+                );
+                self.empty_values(value, key);
+            }
+        }
+    }
+
+    /// Reports the empty strings of a value, recursively.
+    fn empty_values(&mut self, value: &Value, key: &str) {
+        match value {
+            Value::Object(object) => {
+                for (name, value) in object {
+                    self.empty_values(value, &join(key, name));
+                }
+            }
+            Value::String(text) if text.trim().is_empty() => {
+                self.push(IssueKind::EmptyValue, key, format!("`{}` is empty", key))
+            }
+            _ => {}
+        }
+    }
+
+    /// Records an issue about a key of the catalog.
+    fn push(&mut self, kind: IssueKind, key: &str, message: String) {
+        self.issues.push(Issue {
+            kind,
+            file: self.catalog.file.clone(),
+            language: self.catalog.language.clone(),
+            key: Some(key.to_string()),
+            message,
+            position: None,
+        });
+    }
+}
+
+/// Appends a key to a dot-separated key path.
+fn join(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", prefix, name)
+    }
+}
+
+/// Returns the name of the JSON type of a value.
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Returns the indefinite article for a type name.
+fn article(name: &str) -> &'static str {
+    if name.starts_with(['a', 'o']) {
+        "an"
+    } else {
+        "a"
+    }
+}
+
+/// Counts the errors and warnings of a list of issues.
+///
+/// # Returns
+/// - The number of errors and the number of warnings.
+pub fn count(issues: &[Issue]) -> (usize, usize) {
+    let errors = issues
+        .iter()
+        .filter(|issue| issue.severity() == Severity::Error)
+        .count();
+    (errors, issues.len() - errors)
+}
+
+/// Formats issues for humans, one per line, followed by a summary.
+pub fn to_human(issues: &[Issue]) -> String {
+    let mut output = String::new();
+    for issue in issues {
+        let position = issue
+            .position
+            .map(|(line, column)| format!(":{}:{}", line, column))
+            .unwrap_or_default();
+        output.push_str(&format!(
+            "{}[{}] {}{}: {}\n",
+            issue.severity().as_str(),
+            issue.kind.id(),
+            issue.file.display(),
+            position,
+            issue.message
+        ));
+    }
+
+    let (errors, warnings) = count(issues);
+    output.push_str(&format!(
+        "{} error{}, {} warning{}\n",
+        errors,
+        if errors == 1 { "" } else { "s" },
+        warnings,
+        if warnings == 1 { "" } else { "s" }
+    ));
+    output
+}
+
+/// Formats issues as a JSON document with an `issues` array and `errors` and `warnings` counts.
+pub fn to_json(issues: &[Issue]) -> Value {
+    let (errors, warnings) = count(issues);
+    json!({
+        "issues": issues
+            .iter()
+            .map(|issue| json!({
+                "rule": issue.kind.id(),
+                "severity": issue.severity().as_str(),
+                "file": issue.file.to_string_lossy(),
+                "language": issue.language,
+                "key": issue.key,
+                "message": issue.message,
+                "line": issue.position.map(|(line, _)| line),
+                "column": issue.position.map(|(_, column)| column),
+            }))
+            .collect::<Vec<_>>(),
+        "errors": errors,
+        "warnings": warnings,
+    })
+}
+
+/// Formats issues as a SARIF 2.1.0 log, for code scanning tools.
+pub fn to_sarif(issues: &[Issue]) -> Value {
+    let rules: Vec<Value> = IssueKind::ALL
+        .iter()
+        .map(|kind| {
+            json!({
+                "id": kind.id(),
+                "shortDescription": { "text": kind.description() },
+                "defaultConfiguration": { "level": kind.severity().as_str() },
+            })
+        })
+        .collect();
+
+    let results: Vec<Value> = issues
+        .iter()
+        .map(|issue| {
+            let mut location = json!({
+                "physicalLocation": {
+                    "artifactLocation": { "uri": issue.file.to_string_lossy().replace('\\', "/") },
+                }
+            });
+            if let Some((line, column)) = issue.position {
+                location["physicalLocation"]["region"] =
+                    json!({ "startLine": line, "startColumn": column });
+            }
+            json!({
+                "ruleId": issue.kind.id(),
+                "level": issue.severity().as_str(),
+                "message": { "text": issue.message },
+                "locations": [location],
+            })
+        })
+        .collect();
+
+    json!({
+        "version": "2.1.0",
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "i18nrs",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/opensass/i18n-rs",
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }],
+    })
+}
@@ -0,0 +1,196 @@
+/// A piece of a rich-text message, as parsed by `parse_message`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessagePart {
+    /// Literal text.
+    Text(String),
+    /// A `{name}` placeholder, to be replaced by a value.
+    Var(String),
+    /// A `<tag>..</tag>` or `<tag/>` placeholder, to be rendered by the caller around its children.
+    Element {
+        /// The tag name (e.g., `"link"`).
+        tag: String,
+        /// The parts between the opening and closing tags.
+        children: Vec<MessagePart>,
+    },
+}
+
+/// Parses a translated message containing tag placeholders and `{var}` interpolations.
+///
+/// Tags carry no attributes: translators only place them, while the caller decides what each one renders.
+/// Tag and variable names consist of ASCII letters, digits, `_` and `-`. Anything that does not form a
+/// valid, balanced placeholder (e.g., a stray `<` or an unclosed tag) is kept as literal text.
+///
+/// # Arguments
+/// - `message`: The message (e.g., `"By continuing you accept our <link>terms</link>, {name}."`).
+///
+/// # Returns
+/// - The parsed `MessagePart`s, in order.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::message::{parse_message, MessagePart};
+///
+/// let parts = parse_message("Hi {name}, accept our <link>terms</link><br/>");
+/// assert_eq!(
+///     parts,
+///     [
+///         MessagePart::Text("Hi ".to_string()),
+///         MessagePart::Var("name".to_string()),
+///         MessagePart::Text(", accept our ".to_string()),
+///         MessagePart::Element {
+///             tag: "link".to_string(),
+///             children: vec![MessagePart::Text("terms".to_string())],
+///         },
+///         MessagePart::Element { tag: "br".to_string(), children: vec![] },
+///     ]
+/// );
+///
+/// assert_eq!(parse_message("1 < 2 <b>"), [MessagePart::Text("1 < 2 <b>".to_string())]);
+/// ```
+pub fn parse_message(message: &str) -> Vec<MessagePart> {
+    let mut stack = vec![Frame::default()];
+    let mut rest = message;
+
+    while let Some(c) = rest.chars().next() {
+        let token = match c {
+            '<' => parse_tag(rest),
+            '{' => parse_var(rest),
+            _ => None,
+        };
+
+        match token {
+            Some((Token::Open(tag), len)) => {
+                stack.push(Frame {
+                    tag: Some((tag, &rest[..len])),
+                    parts: Vec::new(),
+                });
+                rest = &rest[len..];
+            }
+            Some((Token::SelfClosing(tag), len)) => {
+                push_part(
+                    &mut stack,
+                    MessagePart::Element {
+                        tag: tag.to_string(),
+                        children: Vec::new(),
+                    },
+                );
+                rest = &rest[len..];
+            }
+            Some((Token::Close(tag), len))
+                if stack
+                    .last()
+                    .and_then(|frame| frame.tag)
+                    .map(|(open, _)| open)
+                    == Some(tag) =>
+            {
+                if let Some(frame) = stack.pop() {
+                    push_part(
+                        &mut stack,
+                        MessagePart::Element {
+                            tag: tag.to_string(),
+                            children: frame.parts,
+                        },
+                    );
+                }
+                rest = &rest[len..];
+            }
+            Some((Token::Var(name), len)) => {
+                push_part(&mut stack, MessagePart::Var(name.to_string()));
+                rest = &rest[len..];
+            }
+            Some((Token::Close(_), len)) => {
+                push_text(&mut stack, &rest[..len]);
+                rest = &rest[len..];
+            }
+            None => {
+                push_text(&mut stack, &rest[..c.len_utf8()]);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    // Unclosed tags are literal text, followed by whatever was parsed after them.
+    while stack.len() > 1 {
+        if let Some(frame) = stack.pop() {
+            if let Some((_, raw)) = frame.tag {
+                push_text(&mut stack, raw);
+            }
+            for part in frame.parts {
+                push_part(&mut stack, part);
+            }
+        }
+    }
+
+    stack.pop().map(|frame| frame.parts).unwrap_or_default()
+}
+
+/// A level of nesting of the message parser.
+#[derive(Default)]
+struct Frame<'a> {
+    /// The open tag's name and raw source, or `None` at the top level.
+    tag: Option<(&'a str, &'a str)>,
+    /// The parts parsed so far at this level.
+    parts: Vec<MessagePart>,
+}
+
+/// A placeholder recognized by the message parser.
+enum Token<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    SelfClosing(&'a str),
+    Var(&'a str),
+}
+
+/// Parses `<tag>`, `</tag>` or `<tag/>` at the start of `input`, returning the token and its length.
+fn parse_tag(input: &str) -> Option<(Token<'_>, usize)> {
+    let end = input.find('>')?;
+    let inner = &input[1..end];
+
+    let (token, name) = if let Some(name) = inner.strip_prefix('/') {
+        (Token::Close(name), name)
+    } else if let Some(name) = inner.strip_suffix('/') {
+        let name = name.trim_end();
+        (Token::SelfClosing(name), name)
+    } else {
+        (Token::Open(inner), inner)
+    };
+
+    is_name(name).then_some((token, end + 1))
+}
+
+/// Parses `{name}` at the start of `input`, returning the token and its length.
+fn parse_var(input: &str) -> Option<(Token<'_>, usize)> {
+    let end = input.find('}')?;
+    let name = input[1..end].trim();
+    is_name(name).then_some((Token::Var(name), end + 1))
+}
+
+/// Checks whether a string is a valid tag or variable name.
+fn is_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Appends a part to the innermost open frame, merging adjacent text.
+fn push_part(stack: &mut [Frame], part: MessagePart) {
+    match part {
+        MessagePart::Text(text) => push_text(stack, &text),
+        part => {
+            if let Some(frame) = stack.last_mut() {
+                frame.parts.push(part);
+            }
+        }
+    }
+}
+
+/// Appends literal text to the innermost open frame, merging it with preceding text.
+fn push_text(stack: &mut [Frame], text: &str) {
+    if let Some(frame) = stack.last_mut() {
+        match frame.parts.last_mut() {
+            Some(MessagePart::Text(previous)) => previous.push_str(text),
+            _ => frame.parts.push(MessagePart::Text(text.to_string())),
+        }
+    }
+}
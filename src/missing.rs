@@ -0,0 +1,293 @@
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::future::poll_fn;
+use std::sync::{Arc, Mutex};
+use std::task::{Poll, Waker};
+
+/// Enum representing how a translation lookup missed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MissKind {
+    /// The key exists in neither the requested language nor the default language.
+    Missing,
+    /// The key is missing in the requested language and was served from the default language.
+    Fallback,
+}
+
+/// A missed translation lookup, as reported to the `MissingKeyRecorder` listener.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingKeyEvent {
+    /// The requested language code.
+    pub language: String,
+    /// The translation key (e.g., `"menu.file.open"`).
+    pub key: String,
+    /// Whether the key was missing or served from the default language.
+    pub kind: MissKind,
+}
+
+type MissListener = Arc<dyn Fn(&MissingKeyEvent) + Send + Sync>;
+
+/// The shared state behind a `MissingKeyRecorder`.
+#[derive(Default)]
+struct RecorderState {
+    counts: BTreeMap<(String, MissKind, String), u64>,
+    listener: Option<MissListener>,
+}
+
+/// Records the translation keys that are missing or fall back to the default language.
+///
+/// Attach it with `I18n::record_missing`; every clone of the `I18n` instance then shares it, and
+/// so do clones of the recorder. It is `Send` and `Sync`, so it also works with a shared server instance.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::missing::MissingKeyRecorder;
+/// use i18nrs::{I18n, I18nConfig};
+/// use std::collections::HashMap;
+///
+/// let translations = HashMap::from([
+///     ("en", r#"{"greeting": "Hello", "farewell": "Goodbye"}"#),
+///     ("fr", r#"{"greeting": "Bonjour"}"#),
+/// ]);
+/// let mut i18n = I18n::new(
+///     I18nConfig {
+///         languages: vec!["en", "fr"],
+///         translations: translations.clone(),
+///         ..Default::default()
+///     },
+///     translations,
+/// )
+/// .unwrap();
+///
+/// let recorder = MissingKeyRecorder::default();
+/// i18n.record_missing(Some(recorder.clone()));
+/// i18n.set_language("fr").unwrap();
+///
+/// assert_eq!(i18n.t("farewell"), "Goodbye");
+/// i18n.t("farewell");
+/// i18n.t("title");
+///
+/// let report = recorder.report();
+/// assert_eq!(report["fr"]["fallback"]["farewell"], 2);
+/// assert_eq!(report["fr"]["missing"]["title"], 1);
+/// ```
+#[derive(Clone, Default)]
+pub struct MissingKeyRecorder {
+    state: Arc<Mutex<RecorderState>>,
+}
+
+impl MissingKeyRecorder {
+    /// Records a missed lookup, calling the listener the first time each key misses in a language.
+    ///
+    /// # Arguments
+    /// - `language`: The requested language code.
+    /// - `key`: The translation key.
+    /// - `kind`: How the lookup missed.
+    pub fn record(&self, language: &str, key: &str, kind: MissKind) {
+        let listener = {
+            let Ok(mut state) = self.state.lock() else {
+                return;
+            };
+            let count = state
+                .counts
+                .entry((language.to_string(), kind, key.to_string()))
+                .or_default();
+            *count += 1;
+            if *count > 1 {
+                return;
+            }
+            state.listener.clone()
+        };
+
+        // The listener runs without the lock, so it may translate or read the report.
+        if let Some(listener) = listener {
+            listener(&MissingKeyEvent {
+                language: language.to_string(),
+                key: key.to_string(),
+                kind,
+            });
+        }
+    }
+
+    /// Registers the listener called the first time each key misses in a language.
+    ///
+    /// # Arguments
+    /// - `listener`: The closure to call, replacing any previous one.
+    pub fn set_listener(&self, listener: impl Fn(&MissingKeyEvent) + Send + Sync + 'static) {
+        if let Ok(mut state) = self.state.lock() {
+            state.listener = Some(Arc::new(listener));
+        }
+    }
+
+    /// Forwards the events of the listener to a `MissingKeyEvents` queue, replacing any previous listener.
+    ///
+    /// Use it to handle events with code that is not `Send` (e.g., UI callbacks), from a task awaiting
+    /// `MissingKeyEvents::next`.
+    ///
+    /// # Returns
+    /// - The `MissingKeyEvents` queue receiving the events.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use i18nrs::missing::{MissKind, MissingKeyRecorder};
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let recorder = MissingKeyRecorder::default();
+    ///     let events = recorder.events();
+    ///     recorder.record("fr", "title", MissKind::Missing);
+    ///     events.close();
+    ///
+    ///     assert_eq!(events.next().await.unwrap().key, "title");
+    ///     assert_eq!(events.next().await, None);
+    /// }
+    /// ```
+    pub fn events(&self) -> MissingKeyEvents {
+        let events = MissingKeyEvents::default();
+        let queue = events.queue.clone();
+        self.set_listener(move |event| {
+            let waker = match queue.lock() {
+                Ok(mut queue) if !queue.closed => {
+                    queue.events.push_back(event.clone());
+                    queue.waker.take()
+                }
+                _ => None,
+            };
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        });
+        events
+    }
+
+    /// Removes the listener.
+    pub fn clear_listener(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.listener = None;
+        }
+    }
+
+    /// Retrieves how many times a key missed in a language.
+    ///
+    /// # Arguments
+    /// - `language`: The requested language code.
+    /// - `key`: The translation key.
+    /// - `kind`: How the lookup missed.
+    pub fn count(&self, language: &str, key: &str, kind: MissKind) -> u64 {
+        self.state.lock().ok().map_or(0, |state| {
+            state
+                .counts
+                .get(&(language.to_string(), kind, key.to_string()))
+                .copied()
+                .unwrap_or_default()
+        })
+    }
+
+    /// Exports the recorded lookups, grouped by language and kind, with their counts.
+    ///
+    /// # Returns
+    /// - A JSON object such as `{"fr": {"missing": {"title": 1}, "fallback": {"farewell": 2}}}`, with
+    ///   languages and keys sorted, ready to hand over to translators (e.g., with `to_string_pretty`).
+    pub fn report(&self) -> Value {
+        let mut report = Map::new();
+        if let Ok(state) = self.state.lock() {
+            for ((language, kind, key), count) in &state.counts {
+                let entry = report
+                    .entry(language.clone())
+                    .or_insert_with(|| json!({"missing": {}, "fallback": {}}));
+                let kind = match kind {
+                    MissKind::Missing => "missing",
+                    MissKind::Fallback => "fallback",
+                };
+                entry[kind][key] = json!(count);
+            }
+        }
+        Value::Object(report)
+    }
+
+    /// Forgets every recorded lookup, keeping the listener.
+    pub fn clear(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.counts.clear();
+        }
+    }
+}
+
+impl PartialEq for MissingKeyRecorder {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl fmt::Debug for MissingKeyRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let recorded = self.state.lock().map_or(0, |state| state.counts.len());
+        f.debug_struct("MissingKeyRecorder")
+            .field("recorded", &recorded)
+            .finish()
+    }
+}
+
+/// The events waiting in a `MissingKeyEvents` queue.
+#[derive(Default)]
+struct EventQueue {
+    events: VecDeque<MissingKeyEvent>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+/// A queue of the events of a `MissingKeyRecorder` listener, created with `MissingKeyRecorder::events`.
+///
+/// Clones share the same queue.
+#[derive(Clone, Default)]
+pub struct MissingKeyEvents {
+    queue: Arc<Mutex<EventQueue>>,
+}
+
+impl MissingKeyEvents {
+    /// Waits for the next event.
+    ///
+    /// # Returns
+    /// - `Some(MissingKeyEvent)` with the oldest event in the queue.
+    /// - `None` once the queue is closed and empty.
+    pub async fn next(&self) -> Option<MissingKeyEvent> {
+        poll_fn(|cx| {
+            let Ok(mut queue) = self.queue.lock() else {
+                return Poll::Ready(None);
+            };
+            match queue.events.pop_front() {
+                Some(event) => Poll::Ready(Some(event)),
+                None if queue.closed => Poll::Ready(None),
+                None => {
+                    queue.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+
+    /// Stops queuing events. The events already queued are still returned by `next`.
+    pub fn close(&self) {
+        let waker = self.queue.lock().ok().and_then(|mut queue| {
+            queue.closed = true;
+            queue.waker.take()
+        });
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl fmt::Debug for MissingKeyEvents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (queued, closed) = self
+            .queue
+            .lock()
+            .map_or((0, true), |queue| (queue.events.len(), queue.closed));
+        f.debug_struct("MissingKeyEvents")
+            .field("queued", &queued)
+            .field("closed", &closed)
+            .finish()
+    }
+}
@@ -0,0 +1,242 @@
+use crate::config::I18n;
+
+/// Negotiates the best supported language for a list of requested language tags.
+///
+/// Requested tags are considered in priority order. For each one, the BCP 47 lookup algorithm
+/// (RFC 4647) is applied against `supported`: the tag is matched case-insensitively, then its
+/// subtags are truncated from the end (`"zh-Hant-TW"` → `"zh-Hant"` → `"zh"`) until a match is found.
+/// As a last resort for a tag, a supported language sharing its primary subtag is used
+/// (e.g., `"fr"` matches a supported `"fr-FR"`).
+///
+/// # Arguments
+/// - `requested`: The requested language tags, most preferred first (e.g., `["fr-CH", "en"]`).
+/// - `supported`: The supported language codes (e.g., `["en", "fr"]`).
+///
+/// # Returns
+/// - `Some(&str)` with the matching supported language code.
+/// - `None` if no requested tag matches.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::negotiate::negotiate_language;
+///
+/// assert_eq!(negotiate_language(&["fr-CH", "en"], &["en", "fr"]), Some("fr"));
+/// assert_eq!(negotiate_language(&["pt"], &["en", "pt-BR"]), Some("pt-BR"));
+/// assert_eq!(negotiate_language(&["de"], &["en", "fr"]), None);
+/// ```
+pub fn negotiate_language<'a, S: AsRef<str>>(
+    requested: &[S],
+    supported: &[&'a str],
+) -> Option<&'a str> {
+    requested
+        .iter()
+        .find_map(|tag| match_language(tag.as_ref(), supported))
+}
+
+/// Matches a single requested language tag against the supported languages.
+///
+/// # Arguments
+/// - `tag`: The requested language tag (e.g., `"fr-CH"`).
+/// - `supported`: The supported language codes.
+///
+/// # Returns
+/// - `Some(&str)` with the matching supported language code, or `None`.
+pub(crate) fn match_language<'a>(tag: &str, supported: &[&'a str]) -> Option<&'a str> {
+    let tag = tag.trim().replace('_', "-");
+    if tag.is_empty() || tag == "*" {
+        return None;
+    }
+
+    let mut candidate = tag.as_str();
+    loop {
+        if let Some(language) = supported
+            .iter()
+            .find(|language| language.replace('_', "-").eq_ignore_ascii_case(candidate))
+        {
+            return Some(language);
+        }
+
+        match candidate.rfind('-') {
+            Some(index) => candidate = &candidate[..index],
+            None => break,
+        }
+    }
+
+    supported.iter().copied().find(|language| {
+        language
+            .split(['-', '_'])
+            .next()
+            .is_some_and(|primary| primary.eq_ignore_ascii_case(candidate))
+    })
+}
+
+/// A language range of an `Accept-Language` header, with its quality weight.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageRange {
+    /// The language tag (e.g., `"fr-CH"`), or `"*"` for any language.
+    pub tag: String,
+    /// The quality weight, between `0.0` and `1.0` (defaults to `1.0`).
+    pub quality: f32,
+}
+
+/// The outcome of negotiating an `Accept-Language` header against the supported languages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Negotiation<'a> {
+    /// The best supported language, if any is acceptable.
+    pub best: Option<&'a str>,
+    /// The acceptable supported languages with their quality weights, best first.
+    pub ranked: Vec<(&'a str, f32)>,
+}
+
+/// Parses an `Accept-Language` header.
+///
+/// Ranges are sorted by decreasing quality, keeping the header order for equal weights.
+/// Malformed ranges and ranges with a quality of `0` are dropped.
+///
+/// # Arguments
+/// - `header`: The header value (e.g., `"fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5"`).
+///
+/// # Returns
+/// - The parsed `LanguageRange`s, best first.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::negotiate::parse_accept_language;
+///
+/// let ranges = parse_accept_language("en;q=0.8, fr-CH, fr;q=0.9");
+/// let tags: Vec<&str> = ranges.iter().map(|range| range.tag.as_str()).collect();
+/// assert_eq!(tags, ["fr-CH", "fr", "en"]);
+/// ```
+pub fn parse_accept_language(header: &str) -> Vec<LanguageRange> {
+    let mut ranges: Vec<LanguageRange> = header
+        .split(',')
+        .filter_map(|part| {
+            let mut params = part.split(';').map(str::trim);
+            let tag = params.next().filter(|tag| is_language_range(tag))?;
+
+            let quality = params
+                .find_map(|param| {
+                    param
+                        .strip_prefix("q=")
+                        .or_else(|| param.strip_prefix("Q="))
+                })
+                .map_or(Some(1.0), |quality| quality.trim().parse::<f32>().ok())?;
+
+            (quality > 0.0 && quality <= 1.0).then(|| LanguageRange {
+                tag: tag.to_string(),
+                quality,
+            })
+        })
+        .collect();
+
+    ranges.sort_by(|a, b| b.quality.total_cmp(&a.quality));
+    ranges
+}
+
+/// Negotiates an `Accept-Language` header against the supported languages.
+///
+/// Each range is matched with BCP 47 lookup (see `negotiate_language`); a supported language keeps
+/// the weight of its best matching range. A `*` range ranks the remaining supported languages, in
+/// configuration order, with its own weight. A range refused with `q=0` excludes the supported languages
+/// it covers, i.e., equal to it or more specific (`fr;q=0` excludes `fr` and `fr-CH`, while `fr-CH;q=0`
+/// leaves `fr` acceptable), as in RFC 4647.
+///
+/// # Arguments
+/// - `header`: The header value (e.g., `"fr-CH, fr;q=0.9, en;q=0.8"`).
+/// - `supported`: The supported language codes.
+///
+/// # Returns
+/// - A `Negotiation` with the best match and the ranked acceptable languages.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::negotiate::negotiate_accept_language;
+///
+/// let negotiation = negotiate_accept_language("fr-CH, fr;q=0.9, en;q=0.8", &["en", "es", "fr"]);
+/// assert_eq!(negotiation.best, Some("fr"));
+/// assert_eq!(negotiation.ranked, [("fr", 1.0), ("en", 0.8)]);
+///
+/// let negotiation = negotiate_accept_language("de, *;q=0.1, es;q=0", &["en", "es"]);
+/// assert_eq!(negotiation.best, Some("en"));
+/// assert_eq!(negotiation.ranked, [("en", 0.1)]);
+///
+/// // Refusing a narrower tag keeps the broader language acceptable.
+/// assert_eq!(negotiate_accept_language("fr, fr-CH;q=0", &["fr", "en"]).best, Some("fr"));
+/// assert_eq!(negotiate_accept_language("en-US, en-GB;q=0", &["en"]).best, Some("en"));
+/// assert_eq!(negotiate_accept_language("en-US, en;q=0", &["en-US", "en"]).best, None);
+/// ```
+pub fn negotiate_accept_language<'a>(header: &str, supported: &[&'a str]) -> Negotiation<'a> {
+    let refused: Vec<String> = header
+        .split(',')
+        .filter_map(|part| {
+            let mut params = part.split(';').map(str::trim);
+            let tag = params.next().filter(|tag| *tag != "*")?;
+            params
+                .find_map(|param| {
+                    param
+                        .strip_prefix("q=")
+                        .or_else(|| param.strip_prefix("Q="))
+                })
+                .and_then(|quality| quality.trim().parse::<f32>().ok())
+                .filter(|quality| *quality == 0.0)
+                .map(|_| tag.replace('_', "-"))
+        })
+        .collect();
+    // A refused range excludes the supported tags it covers: equal or more specific ones only.
+    let is_refused = |language: &str| {
+        let language = language.replace('_', "-");
+        refused.iter().any(|range| {
+            language.eq_ignore_ascii_case(range)
+                || (language.len() > range.len()
+                    && language[..range.len()].eq_ignore_ascii_case(range)
+                    && language.as_bytes()[range.len()] == b'-')
+        })
+    };
+
+    let mut ranked: Vec<(&'a str, f32)> = Vec::new();
+    for range in parse_accept_language(header) {
+        let matches: Vec<&'a str> = if range.tag == "*" {
+            supported.to_vec()
+        } else {
+            match_language(&range.tag, supported).into_iter().collect()
+        };
+
+        for language in matches {
+            if !is_refused(language) && !ranked.iter().any(|(l, _)| *l == language) {
+                ranked.push((language, range.quality));
+            }
+        }
+    }
+
+    Negotiation {
+        best: ranked.first().map(|(language, _)| *language),
+        ranked,
+    }
+}
+
+impl I18n {
+    /// Negotiates an `Accept-Language` header against the supported languages of this instance.
+    ///
+    /// # Arguments
+    /// - `header`: The header value (e.g., `"fr-CH, fr;q=0.9, en;q=0.8"`).
+    ///
+    /// # Returns
+    /// - A `Negotiation` over `config.languages`; see `negotiate_accept_language`.
+    pub fn negotiate_accept_language(&self, header: &str) -> Negotiation<'static> {
+        negotiate_accept_language(header, &self.config.languages)
+    }
+}
+
+/// Checks whether a string is a syntactically valid language range (`*` or `alpha *("-" alphanum)`).
+fn is_language_range(tag: &str) -> bool {
+    tag == "*"
+        || (!tag.is_empty()
+            && tag.split('-').enumerate().all(|(index, subtag)| {
+                (1..=8).contains(&subtag.len())
+                    && if index == 0 {
+                        subtag.chars().all(|c| c.is_ascii_alphabetic())
+                    } else {
+                        subtag.chars().all(|c| c.is_ascii_alphanumeric())
+                    }
+            }))
+}
@@ -0,0 +1,278 @@
+use crate::config::I18n;
+use serde_json::Value;
+
+/// The catalog key holding route segment translations.
+///
+/// Each language may map canonical path segments to localized ones:
+/// `{"routes": {"pricing": "tarifs", "about": "a-propos"}}`.
+pub const ROUTES_KEY: &str = "routes";
+
+/// Enum representing where the language is carried in a URL.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum LocaleStrategy {
+    /// The language is the first path segment (e.g., `/fr/pricing`).
+    #[default]
+    PathPrefix,
+    /// The language is a query parameter with the given name (e.g., `/pricing?lang=fr`).
+    QueryParam(String),
+}
+
+/// Reads the language carried by a URL.
+///
+/// # Arguments
+/// - `url`: The path and optional query string (e.g., `"/fr/pricing"` or `"/pricing?lang=fr"`).
+/// - `languages`: The supported language codes.
+/// - `strategy`: Where the language is carried in the URL.
+///
+/// # Returns
+/// - `Some(&str)` with the supported language found in the URL, or `None`.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::routing::{locale_from_url, LocaleStrategy};
+///
+/// let languages = ["en", "fr"];
+/// assert_eq!(locale_from_url("/fr/pricing", &languages, &LocaleStrategy::PathPrefix), Some("fr"));
+/// assert_eq!(
+///     locale_from_url("/pricing?lang=fr", &languages, &LocaleStrategy::QueryParam("lang".into())),
+///     Some("fr")
+/// );
+/// assert_eq!(locale_from_url("/pricing", &languages, &LocaleStrategy::PathPrefix), None);
+/// ```
+pub fn locale_from_url<'a>(
+    url: &str,
+    languages: &[&'a str],
+    strategy: &LocaleStrategy,
+) -> Option<&'a str> {
+    let (path, query) = split_query(url);
+
+    let candidate = match strategy {
+        LocaleStrategy::PathPrefix => path.trim_start_matches('/').split('/').next(),
+        LocaleStrategy::QueryParam(name) => query_pairs(query)
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value),
+    }?;
+
+    languages
+        .iter()
+        .copied()
+        .find(|language| language.eq_ignore_ascii_case(candidate))
+}
+
+/// Removes the language from a URL, yielding the unprefixed path.
+///
+/// # Arguments
+/// - `url`: The path and optional query string.
+/// - `languages`: The supported language codes.
+/// - `strategy`: Where the language is carried in the URL.
+///
+/// # Returns
+/// - The URL without its language (e.g., `"/fr/pricing"` becomes `"/pricing"`).
+pub fn strip_locale(url: &str, languages: &[&str], strategy: &LocaleStrategy) -> String {
+    let (path, query) = split_query(url);
+
+    match strategy {
+        LocaleStrategy::PathPrefix => {
+            let trimmed = path.trim_start_matches('/');
+            let (first, rest) = trimmed.split_once('/').unwrap_or((trimmed, ""));
+
+            let path = if languages
+                .iter()
+                .any(|language| language.eq_ignore_ascii_case(first))
+            {
+                format!("/{}", rest)
+            } else {
+                path.to_string()
+            };
+
+            join_query(&path, query.to_string())
+        }
+        LocaleStrategy::QueryParam(name) => {
+            let query = query_pairs(query)
+                .filter(|(key, _)| key != name)
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect::<Vec<_>>()
+                .join("&");
+
+            join_query(path, query)
+        }
+    }
+}
+
+/// Adds a language to an unprefixed URL.
+///
+/// # Arguments
+/// - `url`: The unprefixed path and optional query string (e.g., `"/pricing"`).
+/// - `language`: The language code to carry.
+/// - `strategy`: Where the language is carried in the URL.
+///
+/// # Returns
+/// - The localized URL (e.g., `"/fr/pricing"` or `"/pricing?lang=fr"`).
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::routing::{localize_url, LocaleStrategy};
+///
+/// assert_eq!(localize_url("/pricing", "fr", &LocaleStrategy::PathPrefix), "/fr/pricing");
+/// assert_eq!(localize_url("/", "fr", &LocaleStrategy::PathPrefix), "/fr");
+/// assert_eq!(
+///     localize_url("/pricing?page=2", "fr", &LocaleStrategy::QueryParam("lang".into())),
+///     "/pricing?page=2&lang=fr"
+/// );
+/// ```
+pub fn localize_url(url: &str, language: &str, strategy: &LocaleStrategy) -> String {
+    let (path, query) = split_query(url);
+
+    match strategy {
+        LocaleStrategy::PathPrefix => {
+            let path = match path.trim_start_matches('/') {
+                "" => format!("/{}", language),
+                rest => format!("/{}/{}", language, rest),
+            };
+
+            join_query(&path, query.to_string())
+        }
+        LocaleStrategy::QueryParam(name) => {
+            let query = query_pairs(query)
+                .filter(|(key, _)| key != name)
+                .map(|(key, value)| format!("{}={}", key, value))
+                .chain(std::iter::once(format!("{}={}", name, language)))
+                .collect::<Vec<_>>()
+                .join("&");
+
+            join_query(path, query)
+        }
+    }
+}
+
+impl I18n {
+    /// Translates the segments of a canonical path into a language.
+    ///
+    /// Segments listed under the `routes` key of the language's translations are replaced by their
+    /// localized slug; other segments (e.g., identifiers) are kept as-is. The query string is preserved.
+    ///
+    /// # Arguments
+    /// - `path`: The canonical path, as produced by `Routable::to_path` (e.g., `"/pricing"`).
+    /// - `language`: The target language code (e.g., `"fr"`).
+    ///
+    /// # Returns
+    /// - The localized path (e.g., `"/tarifs"`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([
+    ///     ("en", r#"{"routes": {}}"#),
+    ///     ("fr", r#"{"routes": {"pricing": "tarifs", "plans": "formules"}}"#),
+    /// ]);
+    /// let i18n = I18n::new(
+    ///     I18nConfig {
+    ///         languages: vec!["en", "fr"],
+    ///         translations: translations.clone(),
+    ///         ..Default::default()
+    ///     },
+    ///     translations,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(i18n.localize_path("/pricing/plans/42", "fr"), "/tarifs/formules/42");
+    /// assert_eq!(i18n.canonicalize_path("/tarifs/formules/42", "fr"), "/pricing/plans/42");
+    /// assert_eq!(i18n.translate_path("/tarifs", "fr", "en"), "/pricing");
+    /// ```
+    pub fn localize_path(&self, path: &str, language: &str) -> String {
+        let routes = self.catalog(language).and_then(|json| json.get(ROUTES_KEY));
+
+        map_segments(path, |segment| {
+            routes
+                .and_then(|routes| routes.get(segment))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        })
+    }
+
+    /// Translates the localized segments of a path back to their canonical form.
+    ///
+    /// This is the inverse of `localize_path`: segments matching a localized slug under the `routes`
+    /// key of the language's translations are replaced by their canonical name.
+    ///
+    /// # Arguments
+    /// - `path`: The localized path (e.g., `"/tarifs"`).
+    /// - `language`: The language of the path (e.g., `"fr"`).
+    ///
+    /// # Returns
+    /// - The canonical path, suitable for `Routable::recognize` (e.g., `"/pricing"`).
+    pub fn canonicalize_path(&self, path: &str, language: &str) -> String {
+        let routes = self
+            .catalog(language)
+            .and_then(|json| json.get(ROUTES_KEY))
+            .and_then(Value::as_object);
+
+        map_segments(path, |segment| {
+            routes?
+                .iter()
+                .find(|(_, slug)| slug.as_str() == Some(segment))
+                .map(|(canonical, _)| canonical.clone())
+        })
+    }
+
+    /// Translates a localized path from one language to another, pointing to the same page.
+    ///
+    /// # Arguments
+    /// - `path`: The path localized in `from` (e.g., `"/tarifs"`).
+    /// - `from`: The language of `path`.
+    /// - `to`: The target language.
+    ///
+    /// # Returns
+    /// - The path localized in `to` (e.g., `"/pricing"`).
+    pub fn translate_path(&self, path: &str, from: &str, to: &str) -> String {
+        self.localize_path(&self.canonicalize_path(path, from), to)
+    }
+}
+
+/// Applies a segment mapping to the path part of a URL, keeping unmapped segments and the query string.
+fn map_segments<F>(url: &str, mut map: F) -> String
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let (path, query) = split_query(url);
+
+    let path = path
+        .split('/')
+        .map(|segment| {
+            if segment.is_empty() {
+                String::new()
+            } else {
+                map(segment).unwrap_or_else(|| segment.to_string())
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    join_query(&path, query.to_string())
+}
+
+/// Splits a URL into its path and query string (without the leading `?`).
+fn split_query(url: &str) -> (&str, &str) {
+    url.split_once('?').unwrap_or((url, ""))
+}
+
+/// Iterates over the `key=value` pairs of a query string.
+fn query_pairs(query: &str) -> impl Iterator<Item = (&str, &str)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+}
+
+/// Joins a path and a query string, omitting the `?` when the query is empty.
+fn join_query(path: &str, query: String) -> String {
+    let path = if path.is_empty() { "/" } else { path };
+
+    if query.is_empty() {
+        path.to_string()
+    } else {
+        format!("{}?{}", path, query)
+    }
+}
@@ -0,0 +1,241 @@
+use crate::cookie::CookieOptions;
+#[cfg(feature = "browser")]
+use crate::cookie::{read_cookie, write_cookie};
+#[cfg(feature = "browser")]
+use gloo_storage::{LocalStorage, SessionStorage, Storage};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// A place where the selected language is persisted.
+///
+/// Implement this trait to persist the language in a custom backend (e.g., a user profile API or
+/// IndexedDB) and plug it in with `StorageType::Custom`.
+///
+/// Methods are synchronous because the initial language must be known before the first render.
+/// Asynchronous backends should serve `read` from a cache and complete `write` and `clear` in the
+/// background (e.g., with `wasm_bindgen_futures::spawn_local`), reporting failures on their own.
+pub trait LanguageStore {
+    /// Reads the persisted language.
+    ///
+    /// # Arguments
+    /// - `key`: The key under which the language is stored.
+    ///
+    /// # Returns
+    /// - `Some(String)` with the stored language code, or `None` if nothing is stored.
+    fn read(&self, key: &str) -> Option<String>;
+
+    /// Persists the selected language.
+    ///
+    /// # Arguments
+    /// - `key`: The key under which the language is stored.
+    /// - `language`: The language code to store.
+    ///
+    /// # Returns
+    /// - `Err(String)` if the language could not be persisted.
+    fn write(&self, key: &str, language: &str) -> Result<(), String>;
+
+    /// Removes the persisted language.
+    ///
+    /// # Arguments
+    /// - `key`: The key under which the language is stored.
+    ///
+    /// # Returns
+    /// - `Err(String)` if the language could not be removed.
+    fn clear(&self, key: &str) -> Result<(), String>;
+
+    /// Whether the language is kept in the browser's `LocalStorage`, whose writes fire `storage` events in
+    /// the other tabs. `LanguageSync` only follows these events for such stores.
+    ///
+    /// # Returns
+    /// - `true` for `LocalStorageStore` with the `browser` feature; `false` by default.
+    fn is_local_storage(&self) -> bool {
+        false
+    }
+}
+
+/// The error returned by browser stores outside a browser (i.e., not on `wasm32`, or without the
+/// `browser` feature).
+const BROWSER_UNAVAILABLE: &str =
+    "Browser storage requires the `browser` feature on a wasm32 target";
+
+/// Checks that browser storage is reachable, so native callers get an error instead of an abort.
+#[cfg(feature = "browser")]
+fn in_browser() -> Result<(), String> {
+    if cfg!(target_arch = "wasm32") {
+        Ok(())
+    } else {
+        Err(BROWSER_UNAVAILABLE.to_string())
+    }
+}
+
+/// Persists the language in the browser's `LocalStorage`.
+///
+/// Outside a browser (i.e., not on `wasm32`, or without the `browser` feature), nothing is read and
+/// writes fail.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::storage::{LanguageStore, LocalStorageStore};
+///
+/// // Doctests run natively, where there is no `LocalStorage`.
+/// assert_eq!(LocalStorageStore.read("i18nrs"), None);
+/// assert!(LocalStorageStore.write("i18nrs", "fr").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LocalStorageStore;
+
+#[cfg(feature = "browser")]
+impl LanguageStore for LocalStorageStore {
+    fn read(&self, key: &str) -> Option<String> {
+        in_browser().ok()?;
+        LocalStorage::get(key).ok()
+    }
+
+    fn write(&self, key: &str, language: &str) -> Result<(), String> {
+        in_browser()?;
+        LocalStorage::set(key, language).map_err(|_| "Failed to write to LocalStorage".to_string())
+    }
+
+    fn clear(&self, key: &str) -> Result<(), String> {
+        in_browser()?;
+        LocalStorage::delete(key);
+        Ok(())
+    }
+
+    fn is_local_storage(&self) -> bool {
+        true
+    }
+}
+
+/// Persists the language in the browser's `SessionStorage`.
+///
+/// Outside a browser (i.e., not on `wasm32`, or without the `browser` feature), nothing is read and
+/// writes fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SessionStorageStore;
+
+#[cfg(feature = "browser")]
+impl LanguageStore for SessionStorageStore {
+    fn read(&self, key: &str) -> Option<String> {
+        in_browser().ok()?;
+        SessionStorage::get(key).ok()
+    }
+
+    fn write(&self, key: &str, language: &str) -> Result<(), String> {
+        in_browser()?;
+        SessionStorage::set(key, language)
+            .map_err(|_| "Failed to write to SessionStorage".to_string())
+    }
+
+    fn clear(&self, key: &str) -> Result<(), String> {
+        in_browser()?;
+        SessionStorage::delete(key);
+        Ok(())
+    }
+}
+
+/// Persists the language in a cookie named after the key.
+///
+/// Outside a browser (i.e., not on `wasm32`, or without the `browser` feature), nothing is read and
+/// writes fail.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CookieStore {
+    /// The attributes of the cookie.
+    pub options: CookieOptions,
+}
+
+#[cfg(feature = "browser")]
+impl LanguageStore for CookieStore {
+    fn read(&self, key: &str) -> Option<String> {
+        in_browser().ok()?;
+        read_cookie(key)
+    }
+
+    fn write(&self, key: &str, language: &str) -> Result<(), String> {
+        in_browser()?;
+        write_cookie(key, language, &self.options)
+    }
+
+    fn clear(&self, key: &str) -> Result<(), String> {
+        in_browser()?;
+        let expired = CookieOptions {
+            max_age: Some(0),
+            ..self.options.clone()
+        };
+        write_cookie(key, "", &expired)
+    }
+}
+
+/// Implements `LanguageStore` for browser stores compiled without the `browser` feature.
+#[cfg(not(feature = "browser"))]
+macro_rules! unavailable_store {
+    ($($store:ty),*) => {
+        $(
+            impl LanguageStore for $store {
+                fn read(&self, _key: &str) -> Option<String> {
+                    None
+                }
+
+                fn write(&self, _key: &str, _language: &str) -> Result<(), String> {
+                    Err(BROWSER_UNAVAILABLE.to_string())
+                }
+
+                fn clear(&self, _key: &str) -> Result<(), String> {
+                    Err(BROWSER_UNAVAILABLE.to_string())
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "browser"))]
+unavailable_store!(LocalStorageStore, SessionStorageStore, CookieStore);
+
+/// Keeps the language in memory, for tests and non-browser targets.
+///
+/// Clones share the same entries, so a test can keep a handle and inspect what was persisted.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::storage::{LanguageStore, MemoryStore};
+///
+/// let store = MemoryStore::default();
+/// store.write("i18nrs", "fr").unwrap();
+/// assert_eq!(store.clone().read("i18nrs"), Some("fr".to_string()));
+///
+/// store.clear("i18nrs").unwrap();
+/// assert_eq!(store.read("i18nrs"), None);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    entries: Arc<Mutex<HashMap<String, String>>>,
+}
+
+impl LanguageStore for MemoryStore {
+    fn read(&self, key: &str) -> Option<String> {
+        self.entries.lock().ok()?.get(key).cloned()
+    }
+
+    fn write(&self, key: &str, language: &str) -> Result<(), String> {
+        self.entries
+            .lock()
+            .map_err(|_| "Failed to write to MemoryStore".to_string())?
+            .insert(key.to_string(), language.to_string());
+        Ok(())
+    }
+
+    fn clear(&self, key: &str) -> Result<(), String> {
+        self.entries
+            .lock()
+            .map_err(|_| "Failed to clear MemoryStore".to_string())?
+            .remove(key);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for dyn LanguageStore + '_ {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LanguageStore")
+    }
+}
@@ -0,0 +1,735 @@
+use crate::config::{I18n, I18nConfig};
+use crate::detect::{detect_language_with_source, DetectionOptions, LanguageSource};
+use crate::storage::{LanguageStore, MemoryStore};
+use crate::sync::LanguageSync;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+/// Enum representing what triggered a language change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeSource {
+    /// The user picked the language (e.g., in a language selector).
+    User,
+    /// The language was read from the persisted storage.
+    Storage,
+    /// The language was detected from the browser's preferences, or is the default language.
+    Detection,
+    /// The language was read from the page URL.
+    Url,
+    /// Another tab switched languages.
+    OtherTab,
+    /// The language was resolved by the server and embedded in the page.
+    Server,
+    /// Any other cause (e.g., the current language was removed from the catalog).
+    Other,
+}
+
+impl From<LanguageSource> for ChangeSource {
+    fn from(source: LanguageSource) -> Self {
+        match source {
+            LanguageSource::Storage => ChangeSource::Storage,
+            LanguageSource::Url => ChangeSource::Url,
+            LanguageSource::Navigator | LanguageSource::Default => ChangeSource::Detection,
+        }
+    }
+}
+
+/// A language change, applied or about to be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageChangeEvent {
+    /// The language in use before the change, or `None` for the initial language.
+    pub previous: Option<String>,
+    /// The new language code.
+    pub language: String,
+    /// What triggered the change.
+    pub source: ChangeSource,
+}
+
+/// Enum representing the answer of a `before_change` guard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChangeDecision {
+    /// Apply the change.
+    #[default]
+    Allow,
+    /// Drop the change.
+    Cancel,
+    /// Keep the change pending until `I18nStore::confirm_change` or `I18nStore::cancel_change` is called.
+    Defer,
+}
+
+/// Enum representing the changes broadcast by an `I18nStore` to its subscribers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StoreEvent {
+    /// The current language changed.
+    LanguageChanged(LanguageChangeEvent),
+    /// The catalog was replaced or mutated (e.g., with `I18nStore::reload` or `I18nStore::update`).
+    CatalogChanged,
+    /// A language change was deferred by the `before_change` guard.
+    ChangeDeferred(LanguageChangeEvent),
+    /// A language change was cancelled by the `before_change` guard, or a deferred one was dropped.
+    ChangeCancelled(LanguageChangeEvent),
+    /// The language could not be persisted; the switch itself succeeded.
+    Error(String),
+}
+
+/// The identifier of a subscription, used to unsubscribe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+type Listener = Rc<dyn Fn(&StoreEvent)>;
+type Guard = Rc<dyn Fn(&LanguageChangeEvent) -> ChangeDecision>;
+
+/// Where the language is read from and, unless `persist` is `false`, persisted to.
+#[derive(Debug, Clone)]
+struct Storage {
+    store: Rc<dyn LanguageStore>,
+    name: String,
+    persist: bool,
+}
+
+/// The detection pipeline configured with `I18nStore::detect_from`.
+#[derive(Debug, Clone)]
+struct Detection {
+    sources: Vec<LanguageSource>,
+    query_param: String,
+    default_language: Option<String>,
+}
+
+impl Default for Detection {
+    fn default() -> Self {
+        Detection {
+            sources: vec![LanguageSource::Storage, LanguageSource::Default],
+            query_param: "lang".to_string(),
+            default_language: None,
+        }
+    }
+}
+
+/// The shared state behind an `I18nStore`.
+struct StoreState {
+    i18n: I18n,
+    storage: Option<Storage>,
+    detection: Detection,
+    sync: Option<LanguageSync>,
+    listeners: Vec<(SubscriptionId, Listener)>,
+    next_id: u64,
+    guard: Option<Guard>,
+    pending: Option<LanguageChangeEvent>,
+}
+
+/// A framework-agnostic, observable owner of the `I18n` state.
+///
+/// The store detects the initial language, switches languages, persists them, keeps them in sync
+/// across tabs and reloads catalogs, broadcasting a `StoreEvent` to every subscriber. The Yew, Dioxus
+/// and Leptos providers build one from their properties and only subscribe to re-render, while non-UI
+/// code (e.g., a service worker bridge) can follow language changes too. Clones share the same state.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::store::{ChangeSource, I18nStore, LanguageChangeEvent, StoreEvent};
+/// use i18nrs::storage::{LanguageStore, MemoryStore};
+/// use i18nrs::{I18n, I18nConfig};
+/// use std::cell::RefCell;
+/// use std::collections::HashMap;
+/// use std::rc::Rc;
+///
+/// let translations = HashMap::from([
+///     ("en", r#"{"greeting": "Hello"}"#),
+///     ("fr", r#"{"greeting": "Bonjour"}"#),
+/// ]);
+/// let i18n = I18n::new(
+///     I18nConfig {
+///         languages: vec!["en", "fr"],
+///         translations: translations.clone(),
+///         ..Default::default()
+///     },
+///     translations,
+/// )
+/// .unwrap();
+///
+/// let memory = MemoryStore::default();
+/// let store = I18nStore::new(i18n).persist_to(Rc::new(memory.clone()), "i18nrs");
+///
+/// let events = Rc::new(RefCell::new(Vec::new()));
+/// let id = store.subscribe({
+///     let events = events.clone();
+///     move |event| events.borrow_mut().push(event.clone())
+/// });
+///
+/// store.set_language("fr").unwrap();
+/// assert_eq!(store.t("greeting"), "Bonjour");
+/// assert_eq!(memory.read("i18nrs"), Some("fr".to_string()));
+/// assert_eq!(
+///     *events.borrow(),
+///     [StoreEvent::LanguageChanged(LanguageChangeEvent {
+///         previous: Some("en".to_string()),
+///         language: "fr".to_string(),
+///         source: ChangeSource::User,
+///     })]
+/// );
+///
+/// assert!(store.unsubscribe(id));
+/// store.set_language("en").unwrap();
+/// assert_eq!(events.borrow().len(), 1);
+/// ```
+#[derive(Clone)]
+pub struct I18nStore {
+    state: Rc<RefCell<StoreState>>,
+}
+
+impl I18nStore {
+    /// Creates a store owning the given state, without persistence.
+    ///
+    /// # Arguments
+    /// - `i18n`: The initial `I18n` state.
+    pub fn new(i18n: I18n) -> Self {
+        I18nStore {
+            state: Rc::new(RefCell::new(StoreState {
+                i18n,
+                storage: None,
+                detection: Detection::default(),
+                sync: None,
+                listeners: Vec::new(),
+                next_id: 0,
+                guard: None,
+                pending: None,
+            })),
+        }
+    }
+
+    /// Persists every language switch in the given storage, which `LanguageSource::Storage` also reads.
+    ///
+    /// # Arguments
+    /// - `store`: Where to persist the language (e.g., `StorageType::resolve`).
+    /// - `storage_name`: The key under which the language is stored.
+    pub fn persist_to(self, store: Rc<dyn LanguageStore>, storage_name: impl Into<String>) -> Self {
+        self.use_storage(store, storage_name.into(), true)
+    }
+
+    /// Reads the persisted language from the given storage for `LanguageSource::Storage`, without
+    /// persisting language switches.
+    ///
+    /// # Arguments
+    /// - `store`: Where the language is persisted (e.g., `StorageType::resolve`).
+    /// - `storage_name`: The key under which the language is stored.
+    pub fn read_from(self, store: Rc<dyn LanguageStore>, storage_name: impl Into<String>) -> Self {
+        self.use_storage(store, storage_name.into(), false)
+    }
+
+    /// Sets the storage of the store.
+    fn use_storage(self, store: Rc<dyn LanguageStore>, name: String, persist: bool) -> Self {
+        self.state.borrow_mut().storage = Some(Storage {
+            store,
+            name,
+            persist,
+        });
+        self
+    }
+
+    /// Configures the detection pipeline run by `detect` and `load_language`.
+    ///
+    /// Without it, the persisted language is used, or else the first supported language.
+    ///
+    /// # Arguments
+    /// - `sources`: The sources to consult, in order. The first one yielding a supported language wins.
+    /// - `query_param`: The name of the URL query parameter read by `LanguageSource::Url`.
+    /// - `default_language`: The language returned by `LanguageSource::Default`.
+    pub fn detect_from(
+        self,
+        sources: Vec<LanguageSource>,
+        query_param: impl Into<String>,
+        default_language: impl Into<String>,
+    ) -> Self {
+        self.state.borrow_mut().detection = Detection {
+            sources,
+            query_param: query_param.into(),
+            default_language: Some(default_language.into()),
+        };
+        self
+    }
+
+    /// Runs the detection pipeline among the supported languages, without applying its result.
+    ///
+    /// # Returns
+    /// - The detected language code, and the source that yielded it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use i18nrs::store::I18nStore;
+    /// use i18nrs::{I18n, I18nConfig, LanguageSource, LanguageStore, MemoryStore};
+    /// use std::collections::HashMap;
+    /// use std::rc::Rc;
+    ///
+    /// let translations = HashMap::from([("en", "{}"), ("fr", "{}")]);
+    /// let i18n = I18n::new(I18nConfig::new(vec!["en", "fr"], translations.clone()), translations).unwrap();
+    ///
+    /// let memory = MemoryStore::default();
+    /// memory.write("i18nrs", "fr-CA").unwrap();
+    /// let store = I18nStore::new(i18n)
+    ///     .persist_to(Rc::new(memory), "i18nrs")
+    ///     .detect_from(vec![LanguageSource::Storage, LanguageSource::Default], "lang", "en");
+    ///
+    /// assert_eq!(store.detect(), ("fr".to_string(), LanguageSource::Storage));
+    /// store.load_language().unwrap();
+    /// assert_eq!(store.language(), "fr");
+    /// ```
+    pub fn detect(&self) -> (String, LanguageSource) {
+        let (storage, detection, languages) = {
+            let state = self.state.borrow();
+            (
+                state.storage.clone(),
+                state.detection.clone(),
+                state.i18n.config.languages.clone(),
+            )
+        };
+        let empty = MemoryStore::default();
+        let (store, storage_name): (&dyn LanguageStore, &str) = match &storage {
+            Some(storage) => (&*storage.store, &storage.name),
+            None => (&empty, ""),
+        };
+
+        detect_language_with_source(&DetectionOptions {
+            sources: &detection.sources,
+            languages: &languages,
+            store,
+            storage_name,
+            query_param: &detection.query_param,
+            default_language: detection
+                .default_language
+                .as_deref()
+                .or(languages.first().copied())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Picks the language with the detection pipeline, then applies and persists it.
+    ///
+    /// Subscribers are not notified and the `before_change` guard is not consulted, as this is meant
+    /// to run before anything is rendered.
+    ///
+    /// # Returns
+    /// - `Ok(LanguageChangeEvent)` describing the initial language, with no `previous` language.
+    /// - `Err(String)` if the detected language is not supported or could not be persisted.
+    pub fn load_language(&self) -> Result<LanguageChangeEvent, String> {
+        let (language, source) = self.detect();
+        let mut state = self.state.borrow_mut();
+        state.i18n.set_language(&language)?;
+        if let Some(storage) = state.storage.as_ref().filter(|storage| storage.persist) {
+            state
+                .i18n
+                .persist_language(&*storage.store, &storage.name)?;
+        }
+        Ok(LanguageChangeEvent {
+            previous: None,
+            language,
+            source: source.into(),
+        })
+    }
+
+    /// Runs the detection pipeline again and switches to its language if it differs from the current one.
+    ///
+    /// Use it after hydrating a server-rendered page, once the first render matched the server markup.
+    ///
+    /// # Returns
+    /// - `Err(String)` if the detected language is not supported.
+    pub fn redetect(&self) -> Result<(), String> {
+        let (language, source) = self.detect();
+        if language == self.language() {
+            Ok(())
+        } else {
+            self.set_language_from(&language, source.into())
+        }
+    }
+
+    /// Keeps the language in sync with the other tabs of the same origin; see `LanguageSync`.
+    ///
+    /// Language changes coming from other tabs are applied with `ChangeSource::OtherTab`, and the other
+    /// changes are broadcast to them. Any previous synchronization is replaced.
+    ///
+    /// # Arguments
+    /// - `channel_name`: The name of the `BroadcastChannel` to join, or `None` to only watch `storage`
+    ///   events on the storage name, which requires persisting to `LocalStorage`.
+    pub fn sync_tabs(&self, channel_name: Option<&str>) {
+        // Only `LocalStorage` writes reach the other tabs as `storage` events.
+        let storage_name = self
+            .state
+            .borrow()
+            .storage
+            .as_ref()
+            .filter(|storage| storage.store.is_local_storage())
+            .map(|storage| storage.name.clone());
+
+        // A weak reference, so the store can be dropped while its listeners are registered.
+        let state = Rc::downgrade(&self.state);
+        let sync = LanguageSync::new(storage_name.as_deref(), channel_name, move |language| {
+            if let Some(state) = state.upgrade() {
+                let store = I18nStore { state };
+                // Other tabs already persisted their language, and may send the current one.
+                if language != store.language() {
+                    let _ = store.set_language_from(&language, ChangeSource::OtherTab);
+                }
+            }
+        });
+        self.state.borrow_mut().sync = Some(sync);
+    }
+
+    /// Stops synchronizing the language with the other tabs.
+    pub fn stop_sync(&self) {
+        let sync = self.state.borrow_mut().sync.take();
+        drop(sync);
+    }
+
+    /// Switches the current language on behalf of the user; see `set_language_from`.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to switch to (e.g., `"fr"`).
+    ///
+    /// # Returns
+    /// - `Err(String)` if the language is not supported. Nothing changes in that case.
+    pub fn set_language(&self, language: &str) -> Result<(), String> {
+        self.set_language_from(language, ChangeSource::User)
+    }
+
+    /// Switches the current language, persists it and notifies subscribers.
+    ///
+    /// The `before_change` guard, if any, is consulted first and may cancel or defer the switch, which
+    /// is broadcast as `StoreEvent::ChangeCancelled` or `StoreEvent::ChangeDeferred`. A persistence
+    /// failure does not undo the switch; it is broadcast as `StoreEvent::Error`. Switching to the current
+    /// language does nothing: the guard is not consulted and no event is broadcast.
+    ///
+    /// # Arguments
+    /// - `language`: The language code to switch to (e.g., `"fr"`).
+    /// - `source`: What triggered the switch, reported in the `LanguageChangeEvent`.
+    ///
+    /// # Returns
+    /// - `Err(String)` if the language is not supported. Nothing changes in that case.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use i18nrs::store::{ChangeDecision, ChangeSource, I18nStore};
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", "{}"), ("fr", "{}")]);
+    /// let config = I18nConfig {
+    ///     languages: vec!["en", "fr"],
+    ///     translations: translations.clone(),
+    ///     ..Default::default()
+    /// };
+    /// let store = I18nStore::new(I18n::new(config, translations).unwrap());
+    ///
+    /// // Ask before relabeling a form with unsaved input.
+    /// store.before_change(|event| match event.source {
+    ///     ChangeSource::OtherTab => ChangeDecision::Cancel,
+    ///     _ => ChangeDecision::Defer,
+    /// });
+    ///
+    /// store.set_language_from("fr", ChangeSource::OtherTab).unwrap();
+    /// assert_eq!(store.language(), "en");
+    ///
+    /// store.set_language("fr").unwrap();
+    /// assert_eq!(store.pending_change().unwrap().language, "fr");
+    /// assert!(store.confirm_change().unwrap());
+    /// assert_eq!(store.language(), "fr");
+    ///
+    /// // Switching to the current language is a no-op, so nothing is deferred.
+    /// store.set_language("fr").unwrap();
+    /// assert_eq!(store.pending_change(), None);
+    /// ```
+    pub fn set_language_from(&self, language: &str, source: ChangeSource) -> Result<(), String> {
+        let (event, guard) = {
+            let state = self.state.borrow();
+            if !state.i18n.config.languages.contains(&language) {
+                return Err(format!("Language '{}' is not supported", language));
+            }
+            if state.i18n.get_current_language() == language {
+                return Ok(());
+            }
+            let event = LanguageChangeEvent {
+                previous: Some(state.i18n.get_current_language().to_string()),
+                language: language.to_string(),
+                source,
+            };
+            (event, state.guard.clone())
+        };
+
+        // The guard runs without a borrow, so it may read the store.
+        match guard.map_or(ChangeDecision::Allow, |guard| guard(&event)) {
+            ChangeDecision::Allow => self.apply(event),
+            ChangeDecision::Cancel => {
+                self.notify(&StoreEvent::ChangeCancelled(event));
+                Ok(())
+            }
+            ChangeDecision::Defer => {
+                self.state.borrow_mut().pending = Some(event.clone());
+                self.notify(&StoreEvent::ChangeDeferred(event));
+                Ok(())
+            }
+        }
+    }
+
+    /// Registers the guard consulted before every language switch made with `set_language_from`.
+    ///
+    /// # Arguments
+    /// - `guard`: The closure deciding whether to allow, cancel or defer a switch. It replaces any
+    ///   previous guard, and may read the store.
+    pub fn before_change(&self, guard: impl Fn(&LanguageChangeEvent) -> ChangeDecision + 'static) {
+        self.state.borrow_mut().guard = Some(Rc::new(guard));
+    }
+
+    /// Removes the `before_change` guard, so every switch is allowed.
+    pub fn clear_before_change(&self) {
+        self.state.borrow_mut().guard = None;
+    }
+
+    /// Retrieves the language change deferred by the `before_change` guard, if any.
+    pub fn pending_change(&self) -> Option<LanguageChangeEvent> {
+        self.state.borrow().pending.clone()
+    }
+
+    /// Applies the deferred language change, without consulting the guard again.
+    ///
+    /// # Returns
+    /// - `Ok(true)` if a deferred change was applied, `Ok(false)` if there was none.
+    /// - `Err(String)` if its language is no longer supported. The change is dropped in that case.
+    pub fn confirm_change(&self) -> Result<bool, String> {
+        let pending = self.state.borrow_mut().pending.take();
+        match pending {
+            Some(mut event) => {
+                event.previous = Some(self.language());
+                self.apply(event).map(|_| true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Drops the deferred language change and notifies subscribers.
+    ///
+    /// # Returns
+    /// - `true` if a deferred change was dropped.
+    pub fn cancel_change(&self) -> bool {
+        let pending = self.state.borrow_mut().pending.take();
+        match pending {
+            Some(event) => {
+                self.notify(&StoreEvent::ChangeCancelled(event));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Applies, persists and broadcasts a language change.
+    fn apply(&self, event: LanguageChangeEvent) -> Result<(), String> {
+        let persisted = {
+            let mut state = self.state.borrow_mut();
+            state.i18n.set_language(&event.language)?;
+            state.pending = None;
+            match state.storage.as_ref().filter(|storage| storage.persist) {
+                Some(storage) => state.i18n.persist_language(&*storage.store, &storage.name),
+                None => Ok(()),
+            }
+        };
+
+        if let Err(err) = persisted {
+            self.notify(&StoreEvent::Error(err));
+        }
+        self.notify(&StoreEvent::LanguageChanged(event));
+        Ok(())
+    }
+
+    /// Replaces the catalog and notifies subscribers.
+    ///
+    /// The current language is kept if it is still supported, otherwise the first language is used.
+    ///
+    /// # Arguments
+    /// - `config`: The new `I18nConfig`.
+    /// - `translations`: A `HashMap` containing language codes as keys and JSON strings as values.
+    ///
+    /// # Returns
+    /// - `Err(String)` if the new catalog is invalid. The previous catalog is kept in that case.
+    pub fn reload(
+        &self,
+        config: I18nConfig,
+        translations: HashMap<&str, &str>,
+    ) -> Result<(), String> {
+        self.replace(I18n::new(config, translations)?);
+        Ok(())
+    }
+
+    /// Replaces the whole state and notifies subscribers.
+    ///
+    /// The current language is kept if `i18n` supports it; otherwise the language of `i18n` is used and
+    /// subscribers are also notified of the language change.
+    ///
+    /// # Arguments
+    /// - `i18n`: The new `I18n` state.
+    pub fn replace(&self, mut i18n: I18n) {
+        let changed = {
+            let mut state = self.state.borrow_mut();
+            let previous = state.i18n.get_current_language().to_string();
+            let _ = i18n.set_language(&previous);
+            state.i18n = i18n;
+            changed_from(&state.i18n, previous)
+        };
+
+        self.notify(&StoreEvent::CatalogChanged);
+        if let Some(event) = changed {
+            self.notify(&StoreEvent::LanguageChanged(event));
+        }
+    }
+
+    /// Mutates the catalog and notifies subscribers.
+    ///
+    /// Use it with `I18n::add_language`, `I18n::remove_language` or `I18n::merge_translations`.
+    /// Subscribers receive `StoreEvent::CatalogChanged`, followed by `StoreEvent::LanguageChanged`
+    /// if the current language changed (e.g., because it was removed).
+    ///
+    /// # Arguments
+    /// - `f`: The mutation. It must not use the store.
+    ///
+    /// # Returns
+    /// - The result of `f`. Subscribers are not notified when it is an `Err`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use i18nrs::store::I18nStore;
+    /// use i18nrs::{I18n, I18nConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let translations = HashMap::from([("en", r#"{"greeting": "Hello"}"#)]);
+    /// let config = I18nConfig {
+    ///     languages: vec!["en"],
+    ///     translations: translations.clone(),
+    ///     ..Default::default()
+    /// };
+    /// let store = I18nStore::new(I18n::new(config, translations).unwrap());
+    ///
+    /// store
+    ///     .update(|i18n| i18n.merge_translations("en", r#"{"farewell": "Goodbye"}"#))
+    ///     .unwrap();
+    /// assert_eq!(store.t("farewell"), "Goodbye");
+    /// ```
+    pub fn update<R>(&self, f: impl FnOnce(&mut I18n) -> Result<R, String>) -> Result<R, String> {
+        let (result, changed) = {
+            let mut state = self.state.borrow_mut();
+            let previous = state.i18n.get_current_language().to_string();
+            let result = f(&mut state.i18n)?;
+            (result, changed_from(&state.i18n, previous))
+        };
+
+        self.notify(&StoreEvent::CatalogChanged);
+        if let Some(event) = changed {
+            self.notify(&StoreEvent::LanguageChanged(event));
+        }
+        Ok(result)
+    }
+
+    /// Retrieves a snapshot of the current state.
+    pub fn i18n(&self) -> I18n {
+        self.state.borrow().i18n.clone()
+    }
+
+    /// Runs a closure with a reference to the current state, without cloning it.
+    ///
+    /// # Arguments
+    /// - `f`: The closure to run. It must not modify the store.
+    pub fn with<R>(&self, f: impl FnOnce(&I18n) -> R) -> R {
+        f(&self.state.borrow().i18n)
+    }
+
+    /// Retrieves the current language code.
+    pub fn language(&self) -> String {
+        self.with(|i18n| i18n.get_current_language().to_string())
+    }
+
+    /// Translates a given key in the current language; see `I18n::t`.
+    pub fn t(&self, key: &str) -> String {
+        self.with(|i18n| i18n.t(key))
+    }
+
+    /// Registers a listener called with every `StoreEvent`.
+    ///
+    /// # Arguments
+    /// - `listener`: The closure to call. It may use the store, including switching languages.
+    ///
+    /// # Returns
+    /// - The `SubscriptionId` to pass to `unsubscribe`.
+    pub fn subscribe(&self, listener: impl Fn(&StoreEvent) + 'static) -> SubscriptionId {
+        let mut state = self.state.borrow_mut();
+        let id = SubscriptionId(state.next_id);
+        state.next_id += 1;
+        state.listeners.push((id, Rc::new(listener)));
+        id
+    }
+
+    /// Removes a listener.
+    ///
+    /// # Arguments
+    /// - `id`: The `SubscriptionId` returned by `subscribe`.
+    ///
+    /// # Returns
+    /// - `true` if the listener was registered.
+    pub fn unsubscribe(&self, id: SubscriptionId) -> bool {
+        let mut state = self.state.borrow_mut();
+        let count = state.listeners.len();
+        state
+            .listeners
+            .retain(|(listener_id, _)| *listener_id != id);
+        state.listeners.len() != count
+    }
+
+    /// Calls every listener, without holding a borrow so listeners can use the store.
+    ///
+    /// Language changes are broadcast to the other tabs first, unless they come from one.
+    fn notify(&self, event: &StoreEvent) {
+        let listeners: Vec<Listener> = {
+            let state = self.state.borrow();
+            if let (StoreEvent::LanguageChanged(event), Some(sync)) = (event, &state.sync) {
+                if event.source != ChangeSource::OtherTab {
+                    sync.broadcast(&event.language);
+                }
+            }
+            state
+                .listeners
+                .iter()
+                .map(|(_, listener)| listener.clone())
+                .collect()
+        };
+
+        for listener in listeners {
+            listener(event);
+        }
+    }
+}
+
+/// Describes the language change forced by a catalog change, if the current language changed.
+fn changed_from(i18n: &I18n, previous: String) -> Option<LanguageChangeEvent> {
+    let language = i18n.get_current_language();
+    (language != previous).then(|| LanguageChangeEvent {
+        previous: Some(previous),
+        language: language.to_string(),
+        source: ChangeSource::Other,
+    })
+}
+
+impl PartialEq for I18nStore {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl fmt::Debug for I18nStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.borrow();
+        f.debug_struct("I18nStore")
+            .field("i18n", &state.i18n)
+            .field("storage", &state.storage)
+            .field("detection", &state.detection)
+            .field("sync", &state.sync)
+            .field("listeners", &state.listeners.len())
+            .field("pending", &state.pending)
+            .finish()
+    }
+}
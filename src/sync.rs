@@ -0,0 +1,162 @@
+#[cfg(feature = "browser")]
+use std::rc::Rc;
+#[cfg(feature = "browser")]
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+#[cfg(feature = "browser")]
+use web_sys::{BroadcastChannel, MessageEvent, StorageEvent};
+
+/// A joined `BroadcastChannel` and its message listener.
+#[cfg(feature = "browser")]
+type Channel = (BroadcastChannel, Closure<dyn FnMut(MessageEvent)>);
+
+/// Keeps the language in sync with the other tabs of the same origin.
+///
+/// Two channels are watched:
+/// - `storage` events, fired when another tab writes the language to `LocalStorage` under the storage name.
+///   Events from other storage areas (e.g., `SessionStorage`) are ignored.
+/// - An optional `BroadcastChannel`, which also covers storages without events (e.g., cookies or custom
+///   stores). Languages are sent to it with `broadcast`.
+///
+/// Listeners are removed when the `LanguageSync` is dropped. Outside the browser (i.e., not on `wasm32`,
+/// or without the `browser` feature), nothing is watched and `broadcast` does nothing.
+///
+/// # Examples
+/// ```rust
+/// use i18nrs::sync::LanguageSync;
+///
+/// let sync = LanguageSync::new(Some("i18nrs"), Some("i18nrs"), |language| {
+///     println!("Another tab switched to {}", language);
+/// });
+/// sync.broadcast("fr");
+/// ```
+pub struct LanguageSync {
+    #[cfg(feature = "browser")]
+    storage: Option<Closure<dyn FnMut(StorageEvent)>>,
+    #[cfg(feature = "browser")]
+    channel: Option<Channel>,
+}
+
+impl LanguageSync {
+    /// Starts listening for language changes made in other tabs.
+    ///
+    /// # Arguments
+    /// - `storage_name`: The key under which the language is persisted in `LocalStorage`, or `None` to
+    ///   ignore `storage` events (e.g., when the language is persisted elsewhere).
+    /// - `channel_name`: The name of the `BroadcastChannel` to join, or `None` to only watch storage events.
+    /// - `on_language`: Called with the language code applied by another tab. It may be called with the
+    ///   current language, so callers should ignore languages they already use.
+    pub fn new(
+        storage_name: Option<&str>,
+        channel_name: Option<&str>,
+        on_language: impl Fn(String) + 'static,
+    ) -> Self {
+        #[cfg(feature = "browser")]
+        {
+            if !cfg!(target_arch = "wasm32") {
+                return LanguageSync {
+                    storage: None,
+                    channel: None,
+                };
+            }
+
+            let on_language: Rc<dyn Fn(String)> = Rc::new(on_language);
+            LanguageSync {
+                storage: storage_name.and_then(|name| watch_storage(name, on_language.clone())),
+                channel: channel_name.and_then(|name| join_channel(name, on_language)),
+            }
+        }
+
+        #[cfg(not(feature = "browser"))]
+        {
+            let _ = (storage_name, channel_name, on_language);
+            LanguageSync {}
+        }
+    }
+
+    /// Sends a language change to the other tabs listening on the same `BroadcastChannel`.
+    ///
+    /// # Arguments
+    /// - `language`: The language code now in use (e.g., `"fr"`).
+    pub fn broadcast(&self, language: &str) {
+        #[cfg(feature = "browser")]
+        if let Some((channel, _)) = &self.channel {
+            let _ = channel.post_message(&JsValue::from_str(language));
+        }
+
+        #[cfg(not(feature = "browser"))]
+        let _ = language;
+    }
+}
+
+impl Drop for LanguageSync {
+    fn drop(&mut self) {
+        #[cfg(feature = "browser")]
+        {
+            if let Some(listener) = &self.storage {
+                if let Some(window) = web_sys::window() {
+                    let _ = window.remove_event_listener_with_callback(
+                        "storage",
+                        listener.as_ref().unchecked_ref(),
+                    );
+                }
+            }
+            if let Some((channel, _)) = &self.channel {
+                channel.set_onmessage(None);
+                channel.close();
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for LanguageSync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("LanguageSync");
+        #[cfg(feature = "browser")]
+        debug.field("storage", &self.storage.is_some()).field(
+            "channel",
+            &self.channel.as_ref().map(|(channel, _)| channel.name()),
+        );
+        debug.finish()
+    }
+}
+
+/// Listens for `storage` events on the language persisted in `LocalStorage`.
+#[cfg(feature = "browser")]
+fn watch_storage(
+    storage_name: &str,
+    on_language: Rc<dyn Fn(String)>,
+) -> Option<Closure<dyn FnMut(StorageEvent)>> {
+    let window = web_sys::window()?;
+    let local_storage = window.local_storage().ok()??;
+    let key = storage_name.to_string();
+    let listener = Closure::<dyn FnMut(StorageEvent)>::new(move |event: StorageEvent| {
+        if event.key().as_deref() != Some(key.as_str())
+            || event.storage_area().as_ref() != Some(&local_storage)
+        {
+            return;
+        }
+        // `LocalStorageStore` writes JSON strings; accept raw values written by other code too.
+        if let Some(value) = event.new_value() {
+            on_language(serde_json::from_str(&value).unwrap_or(value));
+        }
+    });
+
+    window
+        .add_event_listener_with_callback("storage", listener.as_ref().unchecked_ref())
+        .ok()?;
+    Some(listener)
+}
+
+/// Joins a `BroadcastChannel`, forwarding the languages received on it.
+#[cfg(feature = "browser")]
+fn join_channel(name: &str, on_language: Rc<dyn Fn(String)>) -> Option<Channel> {
+    let channel = BroadcastChannel::new(name).ok()?;
+    let listener = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+        if let Some(language) = event.data().as_string() {
+            on_language(language);
+        }
+    });
+
+    channel.set_onmessage(Some(listener.as_ref().unchecked_ref()));
+    Some((channel, listener))
+}
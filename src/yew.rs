@@ -1,10 +1,40 @@
 #![doc = include_str!("../YEW.md")]
 
 use crate::config::{I18n, I18nConfig, StorageType};
-use gloo_storage::{LocalStorage, SessionStorage, Storage};
+use crate::detect::{embedded_language, LanguageSource};
+use crate::language::{LanguageInfo, LanguageMetadata};
+use crate::message::{parse_message, MessagePart};
+use crate::missing::{MissingKeyEvent, MissingKeyRecorder};
+use crate::storage::LanguageStore;
+use crate::store::{ChangeDecision, ChangeSource, I18nStore, LanguageChangeEvent, StoreEvent};
 use std::collections::HashMap;
+use std::rc::Rc;
+use web_sys::HtmlSelectElement;
+use yew::platform::spawn_local;
 use yew::prelude::*;
 
+#[cfg(feature = "yew-router")]
+pub mod router;
+
+/// The context provided by `I18nProvider` to its descendants.
+///
+/// Retrieve it with `use_context::<I18nContext>()`, or through `use_translation`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct I18nContext {
+    /// The current i18n state.
+    pub i18n: I18n,
+    /// Callback switching to the given language code, persisting it and emitting `onchange`.
+    pub set_language: Callback<String>,
+    /// The languages that loaded, in configuration order.
+    pub languages: Vec<&'static str>,
+    /// Whether the initial language is still being resolved (i.e., while hydrating a server render).
+    pub loading: bool,
+    /// The errors reported through `onerror` so far, in order.
+    pub errors: Vec<String>,
+    /// The store owning the state. Catalog changes made with `I18nStore::update` re-render consumers.
+    pub store: I18nStore,
+}
+
 /// Properties for the `I18nProvider` component.
 ///
 /// This configuration struct allows you to specify supported languages, translation paths, storage options,
@@ -24,6 +54,13 @@ pub struct I18nProviderConfig {
     #[prop_or_default]
     pub translations: HashMap<&'static str, &'static str>,
 
+    /// Per-language metadata overrides.
+    ///
+    /// Replaces the built-in CLDR metadata (native name, English name, script, direction, flag)
+    /// for the given language codes. Defaults to an empty `HashMap`.
+    #[prop_or_default]
+    pub metadata: HashMap<&'static str, LanguageMetadata>,
+
     /// The child components to be wrapped with the `I18n` context.
     ///
     /// This property allows you to pass child components that will have access to the internationalization context.
@@ -34,6 +71,7 @@ pub struct I18nProviderConfig {
     /// Determines where the selected language is stored in the browser. Options include:
     /// - `StorageType::LocalStorage`: Use the browser's local storage.
     /// - `StorageType::SessionStorage`: Use the browser's session storage.
+    /// - `StorageType::Cookie`: Use a cookie named after `storage_name`, readable by the server.
     ///
     /// Defaults to `StorageType::LocalStorage`.
     #[prop_or_default]
@@ -47,6 +85,14 @@ pub struct I18nProviderConfig {
     #[prop_or("i18nrs".to_string())]
     pub storage_name: String,
 
+    /// Whether to persist the selected language in `storage_type`.
+    ///
+    /// When `false`, language switches only affect the current page (e.g., to preview a language).
+    ///
+    /// Defaults to `true`.
+    #[prop_or(true)]
+    pub persist: bool,
+
     /// Default language if no language is found in storage.
     ///
     /// Specifies the fallback language that will be used if no language is set in the browser's storage.
@@ -55,18 +101,103 @@ pub struct I18nProviderConfig {
     #[prop_or("en".to_string())]
     pub default_language: String,
 
+    /// Sources consulted, in order, to pick the initial language.
+    ///
+    /// The first source yielding a supported language wins; candidates are negotiated against `languages`
+    /// with BCP 47 matching (e.g., a `fr-CH` browser gets `fr`). Available sources:
+    /// - `LanguageSource::Storage`: The language persisted under `storage_name`.
+    /// - `LanguageSource::Url`: The `query_param` query parameter or the first path segment of the URL.
+    /// - `LanguageSource::Navigator`: The browser's preferred languages (`navigator.languages`).
+    /// - `LanguageSource::Default`: The `default_language`.
+    ///
+    /// Defaults to `[Storage, Default]`, so browser detection is opt-in.
+    #[prop_or(vec![LanguageSource::Storage, LanguageSource::Default])]
+    pub detection: Vec<LanguageSource>,
+
+    /// The language resolved by the server for server-side rendering.
+    ///
+    /// Resolve it from the request (e.g., a `StorageType::Cookie` or the `Accept-Language` header).
+    /// When set, the server render uses it without touching browser APIs, and embeds it in the markup
+    /// so the client hydrates with the same language before applying `detection`.
+    ///
+    /// Defaults to `None`.
+    #[prop_or_default]
+    pub initial_language: Option<String>,
+
+    /// The URL query parameter read by `LanguageSource::Url`.
+    ///
+    /// Defaults to `"lang"`.
+    #[prop_or("lang".to_string())]
+    pub query_param: String,
+
+    /// Whether to apply language changes made in other tabs.
+    ///
+    /// The provider listens to `storage` events on `storage_name`, so tabs persisting to `LocalStorage`
+    /// stay in sync without extra setup.
+    ///
+    /// Defaults to `true`.
+    #[prop_or(true)]
+    pub sync_tabs: bool,
+
+    /// The name of a `BroadcastChannel` used to exchange language changes between tabs.
+    ///
+    /// Needed for storages that fire no `storage` events, such as cookies or custom stores.
+    /// Ignored when `sync_tabs` is `false`.
+    ///
+    /// Defaults to `None`.
+    #[prop_or_default]
+    pub sync_channel: Option<String>,
+
     /// Callback when the language changes.
     ///
     /// This callback is triggered whenever the language is changed. It receives the new language code as a `String`.
     #[prop_or_default]
     pub onchange: Callback<String>,
 
+    /// Callback receiving a `LanguageChangeEvent` for every language change, including the initial language.
+    ///
+    /// The event carries the previous language (`None` for the initial one), the new language and what
+    /// triggered the change (`ChangeSource::User`, `Storage`, `Detection`, `Url`, `OtherTab`, ...).
+    #[prop_or_default]
+    pub onlanguagechange: Callback<LanguageChangeEvent>,
+
+    /// Callback consulted before each language switch, deciding whether to apply it.
+    ///
+    /// Return `ChangeDecision::Cancel` to drop the switch, or `ChangeDecision::Defer` to hold it (e.g., while
+    /// asking whether to discard unsaved input), then call `confirm_change` or `cancel_change` on the
+    /// context's `store`. The initial language is not subject to it.
+    ///
+    /// Defaults to `None`, allowing every switch.
+    #[prop_or_default]
+    pub onbeforechange: Option<Callback<LanguageChangeEvent, ChangeDecision>>,
+
+    /// Callback called the first time each key is missing, or served from the default language, in a language.
+    ///
+    /// It is called asynchronously, after the render that missed the key. Setting it enables recording
+    /// of missing keys. Defaults to `None`.
+    #[prop_or_default]
+    pub onmissing: Option<Callback<MissingKeyEvent>>,
+
+    /// The recorder collecting missing keys and fallback-language hits, e.g., to export a report with
+    /// `MissingKeyRecorder::report`. Its listener is replaced by the provider.
+    ///
+    /// Read once, when the provider mounts. Defaults to `None`, or to an internal recorder if `onmissing` is set.
+    #[prop_or_default]
+    pub missing_recorder: Option<MissingKeyRecorder>,
+
     /// Callback for handling errors.
     ///
     /// This callback is triggered whenever an error occurs in the internationalization process.
     /// It receives an error message as a `String`.
     #[prop_or_default]
     pub onerror: Callback<String>,
+
+    /// Content rendered instead of `children` when no language could be loaded.
+    ///
+    /// Languages whose translations are missing or malformed are skipped and reported through `onerror`;
+    /// the fallback is only used when none of them loads. Defaults to nothing.
+    #[prop_or_default]
+    pub fallback: Html,
 }
 
 /// I18nProvider Component
@@ -80,14 +211,27 @@ pub struct I18nProviderConfig {
 ///
 /// - **languages**: A list of supported languages (`Vec<&'static str>`). Default: `["en", "fr"]`.
 /// - **translations**: A mapping of language codes to raw translation content (`HashMap<&'static str, &'static str>`). Default: empty.
+/// - **metadata**: Per-language overrides of the built-in CLDR metadata (`HashMap<&'static str, LanguageMetadata>`). Default: empty.
 /// - **children**: The child components wrapped within the `I18nProvider` to access the i18n context (`Html`).
 /// - **storage_type**: The type of browser storage for the selected language (`StorageType`). Options:
 ///   - `StorageType::LocalStorage`: Uses the browser's local storage (default).
 ///   - `StorageType::SessionStorage`: Uses the browser's session storage.
+///   - `StorageType::Cookie`: Uses a cookie with configurable `CookieOptions`.
 /// - **storage_name**: The key for storing the selected language in the browser's storage (`String`). Default: `"i18nrs"`.
+/// - **persist**: Whether to persist the selected language in `storage_type` (`bool`). Default: `true`.
 /// - **default_language**: The fallback language if no language is found in storage (`String`). Default: `"en"`.
+/// - **detection**: The ordered sources consulted for the initial language (`Vec<LanguageSource>`). Default: `[Storage, Default]`.
+/// - **query_param**: The URL query parameter read by `LanguageSource::Url` (`String`). Default: `"lang"`.
+/// - **initial_language**: The language resolved by the server for SSR (`Option<String>`). Default: `None`.
+/// - **sync_tabs**: Whether to apply language changes made in other tabs (`bool`). Default: `true`.
+/// - **sync_channel**: The `BroadcastChannel` shared with other tabs (`Option<String>`). Default: `None`.
 /// - **onchange**: An optional callback triggered when the language changes (`Option<Callback<String>>`).
+/// - **onlanguagechange**: A callback receiving each `LanguageChangeEvent`, including the initial language (`Callback<LanguageChangeEvent>`).
+/// - **onmissing**: A callback receiving each key missing or falling back in a language (`Option<Callback<MissingKeyEvent>>`). Default: `None`.
+/// - **missing_recorder**: The `MissingKeyRecorder` collecting missing keys (`Option<MissingKeyRecorder>`). Default: `None`.
+/// - **onbeforechange**: A callback allowing, cancelling or deferring each switch (`Option<Callback<LanguageChangeEvent, ChangeDecision>>`). Default: `None`.
 /// - **onerror**: An optional callback triggered when an error occurs in the i18n process (`Option<Callback<String>>`).
+/// - **fallback**: The content rendered when no language could be loaded (`Html`). Default: nothing.
 ///
 /// # Features
 /// - Provides i18n context with support for dynamic language switching.
@@ -165,6 +309,44 @@ pub struct I18nProviderConfig {
 /// }
 /// ```
 ///
+/// ## Detecting the Browser Language
+/// ```rust
+/// use yew::prelude::*;
+/// use i18nrs::yew::I18nProvider;
+/// use i18nrs::LanguageSource;
+/// use std::collections::HashMap;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let translations = HashMap::from([
+///         ("en", r#"{"greeting": "Hello"}"#),
+///         ("es", r#"{"greeting": "Hola"}"#),
+///     ]);
+///
+///     html! {
+///         <I18nProvider
+///             languages={vec!["en", "es"]}
+///             translations={translations}
+///             detection={vec![
+///                 LanguageSource::Storage,
+///                 LanguageSource::Url,
+///                 LanguageSource::Navigator,
+///                 LanguageSource::Default,
+///             ]}
+///         >
+///             <ChildComponent />
+///         </I18nProvider>
+///     }
+/// }
+///
+/// #[function_component(ChildComponent)]
+/// pub fn child_component() -> Html {
+///     html! {
+///         <div>{ "First-time visitors get their browser language!" }</div>
+///     }
+/// }
+/// ```
+///
 /// ## Custom Storage and Fallback Language
 /// ```rust
 /// use yew::prelude::*;
@@ -201,84 +383,851 @@ pub struct I18nProviderConfig {
 /// ```
 ///
 /// # Behavior
-/// - Retrieves the selected language from browser storage based on the `storage_type` and `storage_name`.
-/// - Uses the `default_language` if no language is found in storage.
+/// - Picks the initial language by consulting the `detection` sources in order, negotiating each candidate
+///   against `languages`. By default, the language stored under `storage_type` and `storage_name` is used.
+/// - Uses the `default_language` if no source yields a supported language.
 /// - Initializes and provides the i18n context with translations and language selection capabilities.
 /// - Emits the `onchange` callback when the language changes, passing the new language code.
 /// - Emits the `onerror` callback in case of initialization or runtime errors.
+/// - Rebuilds the catalog when `languages`, `translations` or `metadata` change, keeping the current
+///   language if it is still supported and switching to `default_language` otherwise.
+/// - Never panics on bad translations: each language that fails to load is reported through `onerror`
+///   and left out, and `fallback` is rendered if no language loads at all.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use i18nrs::yew::I18nProvider;
+/// use std::collections::HashMap;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <I18nProvider
+///             languages={vec!["en"]}
+///             translations={HashMap::from([("en", r#"{"greeting": "#)])}
+///             onerror={Callback::from(|err: String| log::error!("{}", err))}
+///             fallback={html! { <p>{ "Translations are unavailable" }</p> }}
+///         >
+///             <h1>{ "Unreachable" }</h1>
+///         </I18nProvider>
+///     }
+/// }
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let rendered = yew::ServerRenderer::<App>::new().hydratable(false).render().await;
+///
+///     assert_eq!(rendered, "<p>Translations are unavailable</p>");
+/// }
+/// ```
+///
+/// # Server-Side Rendering
+/// - Outside the browser (i.e., not on `wasm32`), no browser API is used: the language is `initial_language`,
+///   or `default_language` if unset, and nothing is persisted.
+/// - When `initial_language` is set, the provider renders an empty `<template data-i18nrs-language="..">`
+///   element carrying the language. While hydrating, the client reads it back so its first render matches
+///   the server markup, then runs the `detection` pipeline and switches languages if needed.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use i18nrs::yew::{use_translation, I18nProvider};
+/// use std::collections::HashMap;
+///
+/// #[derive(Properties, PartialEq)]
+/// pub struct AppProps {
+///     pub language: String,
+/// }
+///
+/// #[function_component(App)]
+/// pub fn app(props: &AppProps) -> Html {
+///     let translations = HashMap::from([
+///         ("en", r#"{"greeting": "Hello"}"#),
+///         ("fr", r#"{"greeting": "Bonjour"}"#),
+///     ]);
+///
+///     html! {
+///         <I18nProvider
+///             languages={vec!["en", "fr"]}
+///             translations={translations}
+///             initial_language={Some(props.language.clone())}
+///         >
+///             <Greeting />
+///         </I18nProvider>
+///     }
+/// }
+///
+/// #[function_component(Greeting)]
+/// pub fn greeting() -> Html {
+///     let (i18n, _set_language) = use_translation();
+///     html! { <h1>{ i18n.t("greeting") }</h1> }
+/// }
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     // The language would typically come from a cookie or the `Accept-Language` header.
+///     let props = AppProps { language: "fr".to_string() };
+///     let rendered = yew::ServerRenderer::<App>::with_props(move || props).render().await;
+///
+///     assert!(rendered.contains("Bonjour"));
+///     assert!(rendered.contains(r#"data-i18nrs-language="fr""#));
+/// }
+/// ```
 ///
 /// # Notes
 /// - The `children` property wraps the components that will have access to the i18n context.
 /// - If a translation error occurs, the `onerror` callback (if provided) is triggered with the error message.
-/// - The `set_language` callback is available via context to dynamically change the selected language.
+/// - The provider publishes a single `I18nContext`, holding the state, the `set_language` callback,
+///   the loaded languages, the loading status and the reported errors.
 #[function_component(I18nProvider)]
 pub fn i18n_provider(props: &I18nProviderConfig) -> Html {
-    let initial_language = match props.storage_type {
-        StorageType::LocalStorage => LocalStorage::get(&props.storage_name)
-            .ok()
-            .unwrap_or_else(|| Some(props.default_language.clone())),
-        StorageType::SessionStorage => SessionStorage::get(&props.storage_name)
-            .ok()
-            .unwrap_or_else(|| Some(props.default_language.clone())),
-    };
+    let embedded = use_memo((), |_| {
+        if cfg!(target_arch = "wasm32") {
+            embedded_language()
+        } else {
+            None
+        }
+    });
+
+    let recorder = use_memo((), |_| {
+        props.missing_recorder.clone().or_else(|| {
+            props
+                .onmissing
+                .as_ref()
+                .map(|_| MissingKeyRecorder::default())
+        })
+    });
+    let recorder = (*recorder).clone();
+    // Refreshed on every render, and read by the task forwarding the recorder's events.
+    let onmissing = use_mut_ref(|| None);
+    *onmissing.borrow_mut() = props.onmissing.clone();
+
+    let language_store = use_memo((), |_| props.storage_type.resolve());
+
+    let init = use_memo((), |_| {
+        let (mut i18n, mut errors) = match I18n::new_lenient(
+            I18nConfig {
+                languages: props.languages.clone(),
+                translations: props.translations.clone(),
+                metadata: props.metadata.clone(),
+            },
+            props.translations.clone(),
+        ) {
+            Ok(loaded) => loaded,
+            Err(errors) => {
+                for err in &errors {
+                    props.onerror.emit(err.clone());
+                }
+                return (None, errors, None);
+            }
+        };
+
+        i18n.record_missing(recorder.clone());
+
+        let known_language = match (&*embedded, cfg!(target_arch = "wasm32")) {
+            (Some(language), _) => Some((language.clone(), ChangeSource::Server)),
+            (None, true) => None,
+            (None, false) => Some(match &props.initial_language {
+                Some(language) => (language.clone(), ChangeSource::Server),
+                None => (props.default_language.clone(), ChangeSource::Detection),
+            }),
+        };
+        let result = match &known_language {
+            Some((language, _)) => i18n.set_language(language),
+            None => Ok(()),
+        };
+
+        let store = new_store(i18n, props, &language_store);
+
+        let result = result.and_then(|_| match known_language {
+            Some((language, source)) => Ok(LanguageChangeEvent {
+                previous: None,
+                language,
+                source,
+            }),
+            None => store.load_language(),
+        });
+        let initial = result.map_err(|err| errors.push(err)).ok();
+        for err in &errors {
+            props.onerror.emit(err.clone());
+        }
+        (Some(store), errors, initial)
+    });
+    let store_state = use_state(|| init.0.clone());
+    let store = &*store_state;
+
+    let ctx = use_state(|| store.as_ref().map(I18nStore::i18n));
+    let errors = use_reducer(|| ErrorLog(init.1.clone()));
+    let loading = use_state(|| embedded.is_some());
+    let mounted = use_mut_ref(|| false);
+
+    {
+        let ctx = ctx.setter();
+        let errors = errors.dispatcher();
+        use_effect_with(
+            (
+                store.clone(),
+                props.onchange.clone(),
+                props.onlanguagechange.clone(),
+                props.onerror.clone(),
+            ),
+            move |(store, onchange, onlanguagechange, onerror)| {
+                let subscription = store.as_ref().map(|store| {
+                    let id = store.subscribe({
+                        let store = store.clone();
+                        let onchange = onchange.clone();
+                        let onlanguagechange = onlanguagechange.clone();
+                        let onerror = onerror.clone();
+                        move |event| match event {
+                            StoreEvent::LanguageChanged(event) => {
+                                ctx.set(Some(store.i18n()));
+                                onchange.emit(event.language.clone());
+                                onlanguagechange.emit(event.clone());
+                            }
+                            StoreEvent::CatalogChanged => ctx.set(Some(store.i18n())),
+                            StoreEvent::ChangeDeferred(_) | StoreEvent::ChangeCancelled(_) => {}
+                            StoreEvent::Error(err) => {
+                                errors.dispatch(err.clone());
+                                onerror.emit(err.clone());
+                            }
+                        }
+                    });
+                    (store.clone(), id)
+                });
+
+                move || {
+                    if let Some((store, id)) = subscription {
+                        store.unsubscribe(id);
+                    }
+                }
+            },
+        );
+    }
+
+    {
+        let store_state = store_state.clone();
+        let ctx = ctx.setter();
+        let errors = errors.dispatcher();
+        let props = props.clone();
+        let recorder = recorder.clone();
+        let language_store = language_store.clone();
+        use_effect_with(
+            (
+                props.languages.clone(),
+                props.translations.clone(),
+                props.metadata.clone(),
+            ),
+            move |(languages, translations, metadata)| {
+                // The catalog was built from the initial properties; only later changes rebuild it.
+                if std::mem::replace(&mut *mounted.borrow_mut(), true) {
+                    let report = |err: String| {
+                        errors.dispatch(err.clone());
+                        props.onerror.emit(err);
+                    };
+
+                    match I18n::new_lenient(
+                        I18nConfig {
+                            languages: languages.clone(),
+                            translations: translations.clone(),
+                            metadata: metadata.clone(),
+                        },
+                        translations.clone(),
+                    ) {
+                        Ok((mut i18n, load_errors)) => {
+                            load_errors.into_iter().for_each(report);
+                            let _ = i18n.set_language(&props.default_language);
+                            i18n.record_missing(recorder.clone());
+                            match &*store_state {
+                                Some(store) => store.replace(i18n),
+                                None => {
+                                    // Recovering from a failed initial load: run the initial
+                                    // detection and persistence now.
+                                    let store = new_store(i18n, &props, &language_store);
+                                    match store.load_language() {
+                                        Ok(event) => props.onlanguagechange.emit(event),
+                                        Err(err) => report(err),
+                                    }
+                                    ctx.set(Some(store.i18n()));
+                                    store_state.set(Some(store));
+                                }
+                            }
+                        }
+                        Err(load_errors) => load_errors.into_iter().for_each(report),
+                    }
+                }
+            },
+        );
+    }
 
-    let i18n = I18n::new(
-        I18nConfig {
-            languages: props.languages.clone(),
-            translations: props.translations.clone(),
+    use_effect_with(
+        (store.clone(), props.sync_tabs, props.sync_channel.clone()),
+        |(store, sync_tabs, sync_channel)| {
+            let store = store.clone().filter(|_| *sync_tabs);
+            if let Some(store) = &store {
+                store.sync_tabs(sync_channel.as_deref());
+            }
+
+            move || {
+                if let Some(store) = store {
+                    store.stop_sync();
+                }
+            }
         },
-        props.translations.clone(),
-    )
-    .map(|mut instance| {
-        instance
-            .set_translation_language(
-                &initial_language.unwrap_or_default(),
-                &props.storage_type,
-                &props.storage_name,
-            )
-            .unwrap_or_else(|err| {
-                props.onerror.emit(err);
+    );
+
+    use_effect_with(recorder.clone(), move |recorder| {
+        let events = recorder.as_ref().map(MissingKeyRecorder::events);
+        if let Some(events) = events.clone() {
+            spawn_local(async move {
+                while let Some(event) = events.next().await {
+                    let onmissing = onmissing.borrow().clone();
+                    if let Some(onmissing) = onmissing {
+                        onmissing.emit(event);
+                    }
+                }
             });
-        instance
-    })
-    .unwrap_or_else(|err| {
-        props.onerror.emit(err.clone());
-        panic!("Failed to initialize I18n: {}", err);
-    });
+        }
 
-    let ctx = use_state(|| i18n);
+        let recorder = recorder.clone();
+        move || {
+            if let (Some(recorder), Some(events)) = (recorder, events) {
+                recorder.clear_listener();
+                events.close();
+            }
+        }
+    });
 
-    let onchange = props.onchange.clone();
-    let storage_type = props.storage_type.clone();
-    let storage_name = props.storage_name.clone();
+    use_effect_with(
+        (store.clone(), props.onbeforechange.clone()),
+        |(store, onbeforechange)| {
+            if let Some(store) = store {
+                match onbeforechange.clone() {
+                    Some(onbeforechange) => {
+                        store.before_change(move |event| onbeforechange.emit(event.clone()))
+                    }
+                    None => store.clear_before_change(),
+                }
+            }
+        },
+    );
 
     let set_language = {
-        let ctx = ctx.clone();
+        let store = store.clone();
         Callback::from(move |language: String| {
-            let mut i18n = (*ctx).clone();
-            if i18n
-                .set_translation_language(&language, &storage_type, &storage_name)
-                .is_ok()
-            {
-                ctx.set(i18n);
-
-                onchange.emit(language);
+            if let Some(store) = &store {
+                let _ = store.set_language(&language);
             }
         })
     };
 
+    {
+        let store = store.clone();
+        let hydrated = embedded.is_some();
+        let loading = loading.setter();
+        let initial = init.2.clone();
+        let onlanguagechange = props.onlanguagechange.clone();
+        use_effect_with((), move |_| {
+            if let Some(initial) = initial {
+                onlanguagechange.emit(initial);
+            }
+            if let Some(store) = store.filter(|_| hydrated) {
+                let _ = store.redetect();
+            }
+            loading.set(false);
+        });
+    }
+
+    let (Some(i18n), Some(store)) = ((*ctx).clone(), store.clone()) else {
+        return props.fallback.clone();
+    };
+
+    let ssr_marker = if props.initial_language.is_some() || embedded.is_some() {
+        html! {
+            <template data-i18nrs-language={i18n.get_current_language().to_string()} />
+        }
+    } else {
+        html! {}
+    };
+
+    let context = I18nContext {
+        languages: i18n.config.languages.clone(),
+        i18n,
+        set_language,
+        loading: *loading,
+        errors: errors.0.clone(),
+        store,
+    };
+
     html! {
-        <ContextProvider<I18n> context={(*ctx).clone()}>
-            <ContextProvider<Callback<String>> context={set_language}>
-                { props.children.clone() }
-            </ContextProvider<Callback<String>>>
-        </ContextProvider<I18n>>
+        <ContextProvider<I18nContext> {context}>
+            { ssr_marker }
+            { props.children.clone() }
+        </ContextProvider<I18nContext>>
+    }
+}
+
+/// The errors reported by an `I18nProvider`, in order.
+#[derive(Debug, Default, PartialEq)]
+struct ErrorLog(Vec<String>);
+
+impl Reducible for ErrorLog {
+    type Action = String;
+
+    fn reduce(self: Rc<Self>, err: String) -> Rc<Self> {
+        let mut errors = self.0.clone();
+        errors.push(err);
+        Rc::new(ErrorLog(errors))
     }
 }
 
+/// Wraps a catalog in a store reading and persisting the language, and detecting it, as configured
+/// by the provider's properties.
+fn new_store(
+    i18n: I18n,
+    props: &I18nProviderConfig,
+    language_store: &Rc<dyn LanguageStore>,
+) -> I18nStore {
+    let store = I18nStore::new(i18n);
+    let store = if props.persist {
+        store.persist_to(language_store.clone(), props.storage_name.clone())
+    } else {
+        store.read_from(language_store.clone(), props.storage_name.clone())
+    };
+    store.detect_from(
+        props.detection.clone(),
+        props.query_param.clone(),
+        props.default_language.clone(),
+    )
+}
+
+/// Retrieves the i18n state and the language switcher provided by the nearest `I18nProvider`.
+///
+/// # Panics
+/// - If called outside an `I18nProvider`; use `use_translation_opt` to handle that case.
 #[hook]
 pub fn use_translation() -> (I18n, Callback<String>) {
-    let i18n = use_context::<I18n>().expect("No I18n context provided");
-    let set_language = use_context::<Callback<String>>().expect("No set_language context found");
-    (i18n, set_language)
+    let context = use_context::<I18nContext>().expect("No I18nContext provided");
+    (context.i18n, context.set_language)
+}
+
+/// Retrieves the i18n state and the language switcher, or `None` outside an `I18nProvider`.
+///
+/// Libraries can use it to offer translations optionally, falling back to their own strings.
+///
+/// # Examples
+/// ```rust
+/// use yew::prelude::*;
+/// use i18nrs::yew::use_translation_opt;
+///
+/// #[function_component(SaveButton)]
+/// pub fn save_button() -> Html {
+///     let label = match use_translation_opt() {
+///         Some((i18n, _set_language)) => i18n.t("save"),
+///         None => "Save".to_string(),
+///     };
+///     html! { <button>{ label }</button> }
+/// }
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     // Rendered without an `I18nProvider` above it.
+///     let rendered = yew::ServerRenderer::<SaveButton>::new()
+///         .hydratable(false)
+///         .render()
+///         .await;
+///
+///     assert_eq!(rendered, "<button>Save</button>");
+/// }
+/// ```
+#[hook]
+pub fn use_translation_opt() -> Option<(I18n, Callback<String>)> {
+    use_context::<I18nContext>().map(|context| (context.i18n, context.set_language))
+}
+
+/// Retrieves a translate closure for the current language.
+///
+/// The closure holds the provider's `I18nStore` handle rather than a copy of the catalog, and always
+/// translates in the language current when it is called.
+///
+/// # Examples
+/// ```rust
+/// use yew::prelude::*;
+/// use i18nrs::yew::{use_language, use_set_language, use_t};
+///
+/// #[function_component(Greeting)]
+/// pub fn greeting() -> Html {
+///     let t = use_t();
+///     let language = use_language();
+///     let set_language = use_set_language();
+///
+///     html! {
+///         <button onclick={move |_| set_language.emit("fr".to_string())}>
+///             { t("greeting") } { " (" } { language } { ")" }
+///         </button>
+///     }
+/// }
+/// ```
+///
+/// # Panics
+/// - If called outside an `I18nProvider`.
+#[hook]
+pub fn use_t() -> Rc<dyn Fn(&str) -> String> {
+    let store = use_context::<I18nContext>()
+        .expect("No I18nContext provided")
+        .store;
+    Rc::new(move |key| store.t(key))
+}
+
+/// Retrieves the current language code.
+///
+/// Like `use_t` and `use_languages`, it reads the provider's `I18nStore`, so these hooks agree with each
+/// other even while a language switch is being rendered.
+///
+/// # Panics
+/// - If called outside an `I18nProvider`.
+#[hook]
+pub fn use_language() -> String {
+    use_context::<I18nContext>()
+        .expect("No I18nContext provided")
+        .store
+        .language()
+}
+
+/// Retrieves the callback switching to the given language code.
+///
+/// # Panics
+/// - If called outside an `I18nProvider`.
+#[hook]
+pub fn use_set_language() -> Callback<String> {
+    use_context::<I18nContext>()
+        .expect("No I18nContext provided")
+        .set_language
+}
+
+/// Retrieves the languages that loaded, in configuration order.
+///
+/// # Panics
+/// - If called outside an `I18nProvider`.
+#[hook]
+pub fn use_languages() -> Vec<&'static str> {
+    use_context::<I18nContext>()
+        .expect("No I18nContext provided")
+        .store
+        .with(|i18n| i18n.config.languages.clone())
+}
+
+/// Properties for the `Trans` component.
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct TransProps {
+    /// The translation key of the message (e.g., `"signup.terms"`).
+    pub id: AttrValue,
+
+    /// Renderers of the message's tag placeholders, by tag name.
+    ///
+    /// Each one receives the rendered content between the tags. Tags without a renderer only render
+    /// their content. Defaults to an empty `HashMap`.
+    #[prop_or_default]
+    pub components: HashMap<&'static str, Callback<Html, Html>>,
+
+    /// Values of the message's `{var}` placeholders, by name.
+    ///
+    /// Placeholders without a value are rendered as is. Defaults to an empty `HashMap`.
+    #[prop_or_default]
+    pub values: HashMap<&'static str, Html>,
+}
+
+/// Trans Component
+///
+/// Renders a translated message containing tag placeholders (`<link>terms</link>`, `<br/>`) and `{var}`
+/// interpolations, so translators keep control of word order while the caller renders the elements.
+/// See `message::parse_message` for the syntax.
+///
+/// # Examples
+/// ```rust
+/// use yew::prelude::*;
+/// use i18nrs::yew::{I18nProvider, Trans};
+/// use std::collections::HashMap;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let translations = HashMap::from([
+///         ("en", r#"{"terms": "By continuing, {name}, you accept our <link>terms</link>."}"#),
+///         ("fr", r#"{"terms": "En continuant, {name}, vous acceptez nos <link>conditions</link>."}"#),
+///     ]);
+///
+///     html! {
+///         <I18nProvider languages={vec!["en", "fr"]} {translations} initial_language={Some("fr".to_string())}>
+///             <Terms />
+///         </I18nProvider>
+///     }
+/// }
+///
+/// #[function_component(Terms)]
+/// pub fn terms() -> Html {
+///     let components = HashMap::from([(
+///         "link",
+///         Callback::from(|children: Html| html! { <a href="/terms">{ children }</a> }),
+///     )]);
+///     let values = HashMap::from([("name", html! { <strong>{ "Ada" }</strong> })]);
+///
+///     html! { <p><Trans id="terms" {components} {values} /></p> }
+/// }
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let rendered = yew::ServerRenderer::<App>::new().render().await;
+///     assert!(rendered.contains(r#"<a href="/terms">conditions</a>"#));
+///     assert!(rendered.contains("<strong>Ada</strong>"));
+/// }
+/// ```
+#[function_component(Trans)]
+pub fn trans(props: &TransProps) -> Html {
+    let t = use_t();
+    render_message(&parse_message(&t(&props.id)), props)
+}
+
+/// Renders parsed message parts with the renderers and values of a `Trans` component.
+fn render_message(parts: &[MessagePart], props: &TransProps) -> Html {
+    parts
+        .iter()
+        .map(|part| match part {
+            MessagePart::Text(text) => html! { { text.clone() } },
+            MessagePart::Var(name) => props
+                .values
+                .get(name.as_str())
+                .cloned()
+                .unwrap_or_else(|| html! { { format!("{{{}}}", name) } }),
+            MessagePart::Element { tag, children } => {
+                let children = render_message(children, props);
+                match props.components.get(tag.as_str()) {
+                    Some(render) => render.emit(children),
+                    None => children,
+                }
+            }
+        })
+        .collect()
+}
+
+/// Built-in presentation styles of the `LanguageSelector` component.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LanguageSelectorVariant {
+    /// A native `<select>` dropdown.
+    #[default]
+    Dropdown,
+    /// A group of toggle buttons, one per language.
+    Buttons,
+}
+
+/// State handed to a custom `LanguageSelector` renderer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageSelectorRenderProps {
+    /// Metadata of every supported language, in configuration order.
+    pub languages: Vec<LanguageInfo>,
+    /// The current language code.
+    pub current_language: String,
+    /// Callback switching to the given language code through the provider.
+    pub onselect: Callback<String>,
+}
+
+/// Properties for the `LanguageSelector` component.
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct LanguageSelectorProps {
+    /// The built-in presentation style.
+    ///
+    /// Ignored when `render` is provided. Defaults to `LanguageSelectorVariant::Dropdown`.
+    #[prop_or_default]
+    pub variant: LanguageSelectorVariant,
+
+    /// Whether to prefix each language name with its flag emoji, when one is known.
+    ///
+    /// Defaults to `true`.
+    #[prop_or(true)]
+    pub show_flags: bool,
+
+    /// CSS classes applied to the `<select>` element or to the button group container.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// CSS classes applied to each button of the `Buttons` variant.
+    #[prop_or_default]
+    pub button_class: Classes,
+
+    /// CSS classes added to the button of the current language in the `Buttons` variant.
+    #[prop_or_default]
+    pub active_class: Classes,
+
+    /// Accessible label of the selector, exposed through `aria-label`.
+    ///
+    /// Defaults to `"Select language"`.
+    #[prop_or("Select language".to_string())]
+    pub aria_label: String,
+
+    /// Custom renderer replacing the built-in variants.
+    ///
+    /// Receives a `LanguageSelectorRenderProps` and returns the selector markup, allowing menus or any
+    /// other presentation while reusing the language list and the provider's setter.
+    #[prop_or_default]
+    pub render: Option<Callback<LanguageSelectorRenderProps, Html>>,
+}
+
+/// LanguageSelector Component
+///
+/// A Yew component listing the supported languages by their native names and switching the current
+/// language through the enclosing `I18nProvider`.
+///
+/// # Properties
+/// The component uses the `LanguageSelectorProps` struct for its properties:
+///
+/// - **variant**: The built-in style (`LanguageSelectorVariant`). Default: `Dropdown`.
+/// - **show_flags**: Whether to show flag emojis next to language names (`bool`). Default: `true`.
+/// - **class**: CSS classes of the `<select>` or the button group (`Classes`). Default: empty.
+/// - **button_class**: CSS classes of each button in the `Buttons` variant (`Classes`). Default: empty.
+/// - **active_class**: CSS classes of the current language's button (`Classes`). Default: empty.
+/// - **aria_label**: The accessible label of the selector (`String`). Default: `"Select language"`.
+/// - **render**: An optional custom renderer (`Option<Callback<LanguageSelectorRenderProps, Html>>`).
+///
+/// # Examples
+///
+/// ## Dropdown
+/// ```rust
+/// use yew::prelude::*;
+/// use i18nrs::yew::LanguageSelector;
+///
+/// #[function_component(Header)]
+/// pub fn header() -> Html {
+///     html! {
+///         <LanguageSelector class="border rounded-md p-2" />
+///     }
+/// }
+/// ```
+///
+/// ## Custom Menu
+/// ```rust
+/// use yew::prelude::*;
+/// use i18nrs::yew::{LanguageSelector, LanguageSelectorRenderProps};
+///
+/// #[function_component(Header)]
+/// pub fn header() -> Html {
+///     let render = Callback::from(|props: LanguageSelectorRenderProps| {
+///         html! {
+///             <ul role="menu">
+///                 { for props.languages.into_iter().map(|language| {
+///                     let code = language.code.clone();
+///                     html! {
+///                         <li role="menuitem">
+///                             <button onclick={props.onselect.reform(move |_| code.clone())}>
+///                                 { language.native_name }
+///                             </button>
+///                         </li>
+///                     }
+///                 }) }
+///             </ul>
+///         }
+///     });
+///
+///     html! {
+///         <LanguageSelector render={render} />
+///     }
+/// }
+/// ```
+///
+/// # Behavior
+/// - Lists `config.languages` in configuration order, labeled with their native names.
+/// - Preselects the current language and follows it when it changes.
+/// - Emits the provider's `set_language` callback when the user picks a language.
+///
+/// # Notes
+/// - Both built-in variants rely on native form controls, so they are reachable with `Tab` and operable
+///   with the keyboard. Each option carries a `lang` attribute so screen readers pronounce it correctly.
+/// - The `Buttons` variant exposes the current language through `aria-pressed`.
+#[function_component(LanguageSelector)]
+pub fn language_selector(props: &LanguageSelectorProps) -> Html {
+    let (i18n, set_language) = use_translation();
+
+    let languages = i18n.languages_info();
+    let current_language = i18n.get_current_language().to_string();
+
+    // Yew sets `value` on a `<select>` as an attribute only, so the selection is applied to the element.
+    let select = use_node_ref();
+    {
+        let select = select.clone();
+        use_effect_with(current_language.clone(), move |current_language| {
+            if let Some(select) = select.cast::<HtmlSelectElement>() {
+                select.set_value(current_language);
+            }
+        });
+    }
+
+    if let Some(render) = &props.render {
+        return render.emit(LanguageSelectorRenderProps {
+            languages,
+            current_language,
+            onselect: set_language,
+        });
+    }
+
+    let label = |language: &LanguageInfo| match (&language.flag, props.show_flags) {
+        (Some(flag), true) => format!("{} {}", flag, language.native_name),
+        _ => language.native_name.clone(),
+    };
+
+    match props.variant {
+        LanguageSelectorVariant::Dropdown => {
+            let onchange = {
+                let current_language = current_language.clone();
+                Callback::from(move |event: Event| {
+                    let select = event.target_unchecked_into::<HtmlSelectElement>();
+                    set_language.emit(select.value());
+                    // Show the current language until the switch re-renders the selector, so a
+                    // cancelled or deferred switch leaves the previous language selected.
+                    select.set_value(&current_language);
+                })
+            };
+
+            html! {
+                <select
+                    ref={select}
+                    class={props.class.clone()}
+                    aria-label={props.aria_label.clone()}
+                    value={current_language.clone()}
+                    onchange={onchange}
+                >
+                    { for languages.iter().map(|language| html! {
+                        <option
+                            value={language.code.clone()}
+                            lang={language.code.clone()}
+                        >
+                            { label(language) }
+                        </option>
+                    }) }
+                </select>
+            }
+        }
+        LanguageSelectorVariant::Buttons => {
+            html! {
+                <div class={props.class.clone()} role="group" aria-label={props.aria_label.clone()}>
+                    { for languages.iter().map(|language| {
+                        let code = language.code.clone();
+                        let active = language.code == current_language;
+                        let class = if active {
+                            classes!(props.button_class.clone(), props.active_class.clone())
+                        } else {
+                            props.button_class.clone()
+                        };
+
+                        html! {
+                            <button
+                                type="button"
+                                class={class}
+                                lang={language.code.clone()}
+                                aria-pressed={active.to_string()}
+                                onclick={set_language.reform(move |_| code.clone())}
+                            >
+                                { label(language) }
+                            </button>
+                        }
+                    }) }
+                </div>
+            }
+        }
+    }
 }
@@ -0,0 +1,289 @@
+//! Integration of the i18n context with `yew-router`.
+//!
+//! Keeps the current language in sync with the URL, either as a path prefix (`/fr/pricing`)
+//! or as a query parameter (`/pricing?lang=fr`). Path segments listed under the `routes` key of the
+//! translations are localized as well (`/fr/tarifs`), while `Routable` enums keep their canonical paths.
+
+use crate::routing::{locale_from_url, localize_url, strip_locale, LocaleStrategy};
+use crate::store::ChangeSource;
+use crate::yew::{use_translation, I18nContext};
+use yew::prelude::*;
+use yew_router::prelude::*;
+use yew_router::AnyRoute;
+
+/// Properties for the `LocaleRouter` component.
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct LocaleRouterProps {
+    /// Where the language is carried in the URL.
+    ///
+    /// Defaults to `LocaleStrategy::PathPrefix`.
+    #[prop_or_default]
+    pub strategy: LocaleStrategy,
+
+    /// The child components, typically containing a `LocalizedSwitch`.
+    pub children: Html,
+}
+
+/// LocaleRouter Component
+///
+/// Synchronizes the current language with the URL. It must be rendered inside both a `yew-router`
+/// router and an `I18nProvider`.
+///
+/// # Properties
+/// - **strategy**: Where the language is carried in the URL (`LocaleStrategy`). Default: `PathPrefix`.
+/// - **children**: The routed content (`Html`).
+///
+/// # Examples
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_router::prelude::*;
+/// use i18nrs::yew::I18nProvider;
+/// use i18nrs::yew::router::{LocaleRouter, LocalizedLink, LocalizedSwitch};
+/// use i18nrs::LanguageSource;
+/// use std::collections::HashMap;
+///
+/// #[derive(Clone, Routable, PartialEq)]
+/// pub enum Route {
+///     #[at("/")]
+///     Home,
+///     #[at("/pricing")]
+///     Pricing,
+/// }
+///
+/// fn switch(route: Route) -> Html {
+///     match route {
+///         Route::Home => html! { <LocalizedLink<Route> to={Route::Pricing}>{ "Pricing" }</LocalizedLink<Route>> },
+///         Route::Pricing => html! { <h1>{ "Pricing" }</h1> },
+///     }
+/// }
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let translations = HashMap::from([
+///         ("en", r#"{"greeting": "Hello"}"#),
+///         ("fr", r#"{"greeting": "Bonjour"}"#),
+///     ]);
+///
+///     html! {
+///         <BrowserRouter>
+///             <I18nProvider
+///                 languages={vec!["en", "fr"]}
+///                 translations={translations}
+///                 detection={vec![LanguageSource::Url, LanguageSource::Storage, LanguageSource::Default]}
+///             >
+///                 <LocaleRouter>
+///                     <LocalizedSwitch<Route> render={switch} />
+///                 </LocaleRouter>
+///             </I18nProvider>
+///         </BrowserRouter>
+///     }
+/// }
+/// ```
+///
+/// # Behavior
+/// - When the URL carries a different supported language (e.g., the user follows a `/fr/...` link),
+///   the language is switched through the provider's store, reporting `ChangeSource::Url`.
+/// - When the language is switched by other means (e.g., a `LanguageSelector`), the URL is replaced
+///   with the same page in the new language, translating route slugs (e.g., `/fr/tarifs` becomes `/en/pricing`).
+/// - URLs without a language are rewritten to carry the current one.
+///
+/// # Notes
+/// - Add `LanguageSource::Url` to the provider's `detection` so the first render already uses the URL language.
+#[function_component(LocaleRouter)]
+pub fn locale_router(props: &LocaleRouterProps) -> Html {
+    let context = use_context::<I18nContext>().expect("No I18nContext provided");
+    let (i18n, store) = (context.i18n, context.store);
+    let navigator = use_navigator().expect("No router found for LocaleRouter");
+    let location = use_location().expect("No location found for LocaleRouter");
+
+    let url = current_url(&navigator, &location);
+    let current_language = i18n.get_current_language().to_string();
+    let synced = use_mut_ref(|| (None::<String>, String::new()));
+
+    {
+        let strategy = props.strategy.clone();
+        use_effect_with((url, current_language), move |(url, current_language)| {
+            let languages = &i18n.config.languages;
+            let url_language = locale_from_url(url, languages, &strategy).map(str::to_string);
+            let (synced_url_language, _) = synced.borrow().clone();
+
+            if url_language.is_some()
+                && url_language != synced_url_language
+                && url_language.as_deref() != Some(current_language.as_str())
+            {
+                let _ = store.set_language_from(
+                    url_language.as_deref().unwrap_or_default(),
+                    ChangeSource::Url,
+                );
+            } else if url_language.as_deref() != Some(current_language.as_str()) {
+                let unprefixed = strip_locale(url, languages, &strategy);
+                let path_language = url_language.as_deref().unwrap_or(current_language);
+                let path = i18n.translate_path(&unprefixed, path_language, current_language);
+                navigator.replace(&AnyRoute::new(localize_url(
+                    &path,
+                    current_language,
+                    &strategy,
+                )));
+            }
+
+            *synced.borrow_mut() = (url_language, current_language.clone());
+        });
+    }
+
+    html! {
+        <ContextProvider<LocaleStrategy> context={props.strategy.clone()}>
+            { props.children.clone() }
+        </ContextProvider<LocaleStrategy>>
+    }
+}
+
+/// Properties for the `LocalizedSwitch` component.
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct LocalizedSwitchProps<R>
+where
+    R: Routable,
+{
+    /// Callback returning the `Html` of the matched route.
+    pub render: Callback<R, Html>,
+}
+
+/// LocalizedSwitch Component
+///
+/// A `Switch` matching routes against the canonical URL, without its language and with route slugs
+/// translated back, so a `Routable` enum declared with `#[at("/pricing")]` matches both `/en/pricing`
+/// and `/fr/tarifs` given `{"routes": {"pricing": "tarifs"}}` in the French translations.
+#[function_component(LocalizedSwitch)]
+pub fn localized_switch<R>(props: &LocalizedSwitchProps<R>) -> Html
+where
+    R: Routable + 'static,
+{
+    let (i18n, _set_language) = use_translation();
+    let navigator = use_navigator().expect("No router found for LocalizedSwitch");
+    let location = use_location().expect("No location found for LocalizedSwitch");
+    let strategy = use_context::<LocaleStrategy>().unwrap_or_default();
+
+    let path = strip_basename(&navigator, location.path());
+    let languages = &i18n.config.languages;
+    let path_language =
+        locale_from_url(&path, languages, &strategy).unwrap_or_else(|| i18n.get_current_language());
+    let unprefixed = strip_locale(&path, languages, &strategy);
+    let canonical = i18n.canonicalize_path(&unprefixed, path_language);
+
+    R::recognize(&canonical)
+        .map(|route| props.render.emit(route))
+        .unwrap_or_default()
+}
+
+/// Properties for the `LocalizedLink` component.
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct LocalizedLinkProps<R>
+where
+    R: Routable,
+{
+    /// The route to navigate to, in the current language.
+    pub to: R,
+
+    /// CSS classes applied to the anchor element.
+    #[prop_or_default]
+    pub classes: Classes,
+
+    /// The content of the anchor element.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// LocalizedLink Component
+///
+/// A `Link` keeping the current language in the target URL and localizing route slugs
+/// (e.g., `Route::Pricing` points to `/fr/tarifs` while browsing in French).
+///
+/// # Examples
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_router::history::{AnyHistory, MemoryHistory};
+/// use yew_router::prelude::*;
+/// use i18nrs::yew::I18nProvider;
+/// use i18nrs::yew::router::LocalizedLink;
+/// use std::collections::HashMap;
+///
+/// #[derive(Clone, Routable, PartialEq)]
+/// pub enum Route {
+///     #[at("/pricing")]
+///     Pricing,
+/// }
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let translations = HashMap::from([
+///         ("en", r#"{"routes": {}}"#),
+///         ("fr", r#"{"routes": {"pricing": "tarifs"}}"#),
+///     ]);
+///     let history = AnyHistory::from(MemoryHistory::new());
+///
+///     html! {
+///         <Router {history}>
+///             <I18nProvider
+///                 languages={vec!["en", "fr"]}
+///                 translations={translations}
+///                 initial_language={Some("fr".to_string())}
+///             >
+///                 <LocalizedLink<Route> to={Route::Pricing}>{ "Tarifs" }</LocalizedLink<Route>>
+///             </I18nProvider>
+///         </Router>
+///     }
+/// }
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let rendered = yew::ServerRenderer::<App>::new().render().await;
+///     assert!(rendered.contains(r#"href="/fr/tarifs""#));
+/// }
+/// ```
+#[function_component(LocalizedLink)]
+pub fn localized_link<R>(props: &LocalizedLinkProps<R>) -> Html
+where
+    R: Routable + 'static,
+{
+    let (i18n, _set_language) = use_translation();
+    let navigator = use_navigator().expect("No router found for LocalizedLink");
+    let strategy = use_context::<LocaleStrategy>().unwrap_or_default();
+
+    let language = i18n.get_current_language();
+    let path = i18n.localize_path(&props.to.to_path(), language);
+    let target = localize_url(&path, language, &strategy);
+    let href = format!("{}{}", navigator.basename().unwrap_or_default(), target);
+
+    let onclick = Callback::from(move |event: MouseEvent| {
+        if event.meta_key() || event.ctrl_key() || event.shift_key() || event.alt_key() {
+            return;
+        }
+        event.prevent_default();
+        navigator.push(&AnyRoute::new(target.clone()));
+    });
+
+    html! {
+        <a class={props.classes.clone()} href={href} onclick={onclick}>
+            { props.children.clone() }
+        </a>
+    }
+}
+
+/// Builds the current path and query string, without the router's basename.
+fn current_url(navigator: &Navigator, location: &Location) -> String {
+    let path = strip_basename(navigator, location.path());
+    format!("{}{}", path, location.query_str())
+}
+
+/// Removes the router's basename from a path.
+fn strip_basename(navigator: &Navigator, path: &str) -> String {
+    let path = navigator
+        .basename()
+        .and_then(|basename| path.strip_prefix(basename))
+        .unwrap_or(path);
+
+    if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("/{}", path)
+    }
+}
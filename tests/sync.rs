@@ -0,0 +1,49 @@
+//! Browser tests for `LanguageSync`, run with `wasm-pack test --headless --chrome --features yew`.
+#![cfg(all(target_arch = "wasm32", feature = "browser"))]
+
+use i18nrs::sync::LanguageSync;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{Storage, StorageEvent, StorageEventInit};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Dispatches the `storage` event another tab fires when writing `language` to `area`.
+fn dispatch(area: &Storage, language: &str) {
+    let init = StorageEventInit::new();
+    init.set_key(Some("i18nrs"));
+    init.set_new_value(Some(&format!("\"{}\"", language)));
+    init.set_storage_area(Some(area));
+    let event = StorageEvent::new_with_event_init_dict("storage", &init).unwrap();
+    web_sys::window().unwrap().dispatch_event(&event).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn follows_local_storage_events_only() {
+    let window = web_sys::window().unwrap();
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let _sync = LanguageSync::new(Some("i18nrs"), None, {
+        let received = received.clone();
+        move |language| received.borrow_mut().push(language)
+    });
+
+    dispatch(&window.session_storage().unwrap().unwrap(), "de");
+    dispatch(&window.local_storage().unwrap().unwrap(), "fr");
+
+    assert_eq!(*received.borrow(), ["fr"]);
+}
+
+#[wasm_bindgen_test]
+fn ignores_storage_events_without_storage_name() {
+    let window = web_sys::window().unwrap();
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let _sync = LanguageSync::new(None, None, {
+        let received = received.clone();
+        move |language| received.borrow_mut().push(language)
+    });
+
+    dispatch(&window.local_storage().unwrap().unwrap(), "fr");
+
+    assert!(received.borrow().is_empty());
+}
@@ -0,0 +1,106 @@
+//! Browser tests for the Yew provider, run with `wasm-pack test --headless --chrome --features yew`.
+#![cfg(all(target_arch = "wasm32", feature = "yew"))]
+
+use i18nrs::storage::{LanguageStore, MemoryStore};
+use i18nrs::store::ChangeDecision;
+use i18nrs::yew::{use_translation, I18nProvider, I18nProviderConfig, LanguageSelector};
+use i18nrs::StorageType;
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::{Event, HtmlSelectElement};
+use yew::platform::time::sleep;
+use yew::prelude::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[function_component(Greeting)]
+fn greeting() -> Html {
+    let (i18n, _set_language) = use_translation();
+    html! { <p>{ i18n.t("greeting") }</p> }
+}
+
+/// Creates the element the provider is mounted into.
+fn mount_point() -> web_sys::Element {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let root = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&root).unwrap();
+    root
+}
+
+/// Lets the scheduler render and run the effects.
+async fn settle() {
+    sleep(std::time::Duration::from_millis(50)).await;
+}
+
+#[wasm_bindgen_test]
+async fn rebuild_after_failed_load_detects_language() {
+    let store = MemoryStore::default();
+    store.write("i18nrs", "fr").unwrap();
+    let storage_type = StorageType::Custom(Rc::new(store.clone()));
+
+    let root = mount_point();
+    let mut app = yew::Renderer::<I18nProvider>::with_root_and_props(
+        root.clone(),
+        yew::props!(I18nProviderConfig {
+            languages: vec!["en"],
+            translations: HashMap::from([("en", "{ invalid")]),
+            storage_type: storage_type.clone(),
+            fallback: html! { <p>{ "Loading failed" }</p> },
+            children: html! { <Greeting /> },
+        }),
+    )
+    .render();
+    settle().await;
+    assert_eq!(root.text_content().unwrap(), "Loading failed");
+
+    app.update(yew::props!(I18nProviderConfig {
+        languages: vec!["en", "fr"],
+        translations: HashMap::from([
+            ("en", r#"{"greeting": "Hello"}"#),
+            ("fr", r#"{"greeting": "Bonjour"}"#),
+        ]),
+        storage_type,
+        fallback: html! { <p>{ "Loading failed" }</p> },
+        children: html! { <Greeting /> },
+    }));
+    settle().await;
+
+    // The rebuilt store ran detection, so the stored language wins over the default one.
+    assert_eq!(root.text_content().unwrap(), "Bonjour");
+    app.destroy();
+}
+
+#[wasm_bindgen_test]
+async fn dropdown_keeps_the_current_language_when_a_switch_is_cancelled() {
+    let root = mount_point();
+    let app = yew::Renderer::<I18nProvider>::with_root_and_props(
+        root.clone(),
+        yew::props!(I18nProviderConfig {
+            languages: vec!["en", "fr"],
+            translations: HashMap::from([("en", "{}"), ("fr", "{}")]),
+            storage_type: StorageType::Custom(Rc::new(MemoryStore::default())),
+            onbeforechange: Some(Callback::from(|_| ChangeDecision::Cancel)),
+            children: html! { <LanguageSelector /> },
+        }),
+    )
+    .render();
+    settle().await;
+
+    let select: HtmlSelectElement = root
+        .query_selector("select")
+        .unwrap()
+        .unwrap()
+        .unchecked_into();
+    assert_eq!(select.value(), "en");
+
+    select.set_value("fr");
+    select
+        .dispatch_event(&Event::new("change").unwrap())
+        .unwrap();
+    settle().await;
+
+    assert_eq!(select.value(), "en");
+    app.destroy();
+}